@@ -21,6 +21,7 @@
 //! ```
 //!
 
+use std::borrow::Cow;
 use std::fmt;
 use std::path;
 
@@ -31,13 +32,20 @@ use predicates::str::PredicateStrExt;
 use predicates_tree::CaseTreeExt;
 
 use crate::fixture;
+use crate::fixture::walk_root;
+use crate::predicate::bytes::BytesEqPredicate;
+use crate::predicate::dir;
+use crate::predicate::file::FileEqPredicate;
+use crate::predicate::str::WildcardPredicate;
+use crate::predicate::str::wildcard_eq;
 
 /// Assert the state of files within [`TempDir`].
 ///
 /// This uses [`IntoPathPredicate`] to provide short-hands for common cases, accepting:
 /// - `Predicate<Path>` for validating a path.
 /// - `Predicate<str>` for validating the content of the file.
-/// - `&[u8]` or `&str` representing the content of the file.
+/// - `&[u8]`, `Vec<u8>`, or `&str` representing the content of the file.
+/// - `&Path` or `PathBuf` of a file with the expected content.
 ///
 /// See [`predicates`] for more predicates.
 ///
@@ -66,7 +74,8 @@ pub trait PathAssert {
     /// This uses [`IntoPathPredicate`] to provide short-hands for common cases, accepting:
     /// - `Predicate<Path>` for validating a path.
     /// - `Predicate<str>` for validating the content of the file.
-    /// - `&[u8]` or `&str` representing the content of the file.
+    /// - `&[u8]`, `Vec<u8>`, or `&str` representing the content of the file.
+    /// - `&Path` or `PathBuf` of a file with the expected content.
     ///
     /// See [`predicates`] for more predicates.
     ///
@@ -139,6 +148,208 @@ impl PathAssert for fixture::ChildPath {
     }
 }
 
+/// Assert the content of a [`ChildPath`] or [`TempDir`], ignoring where the [`TempDir`] is.
+///
+/// Before comparing, occurrences of the fixture root are replaced with `[ROOT]` in the content of
+/// the file, both as created and canonicalized.  The root is the [`TempDir`] itself, the
+/// [`TempDir`] a [`ChildPath`] was created from, or the [`ChildPath`] itself when created with
+/// [`ChildPath::new`].
+///
+/// This uses [`IntoStrPredicate`] to accept:
+/// - `Predicate<str>` for validating the redacted content.
+/// - `&str` or `String` representing the redacted content, with the placeholders of
+///   [`wildcard_eq`].
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// let config = temp.child("config.toml");
+/// config
+///     .write_str(&format!("cache = \"{}\"\n", temp.child("cache").display()))
+///     .unwrap();
+///
+/// // ... do something with config ...
+///
+/// config.assert_redacted("cache = \"[ROOT][..]cache\"\n");
+///
+/// temp.close().unwrap();
+/// ```
+///
+/// [`TempDir`]: super::TempDir
+/// [`ChildPath`]: super::fixture::ChildPath
+/// [`ChildPath::new`]: super::fixture::ChildPath::new
+/// [`wildcard_eq`]: crate::predicate::str::wildcard_eq
+pub trait PathAssertRedacted {
+    /// Assert the content of a [`ChildPath`] or [`TempDir`], with the fixture root replaced by
+    /// `[ROOT]`.
+    ///
+    /// See [`PathAssertRedacted`] for details.
+    ///
+    /// # Panic
+    ///
+    /// Will panic if the condition is not satisfied
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    /// use predicates::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let log = temp.child("build.log");
+    /// log.write_str(&format!("Compiling {}\n", temp.path().display()))
+    ///     .unwrap();
+    ///
+    /// log.assert_redacted("Compiling [ROOT]\n");
+    /// log.assert_redacted(predicate::str::contains("[ROOT]"));
+    ///
+    /// temp.close().unwrap();
+    /// ```
+    ///
+    /// [`ChildPath`]: super::fixture::ChildPath
+    /// [`TempDir`]: super::TempDir
+    #[track_caller]
+    fn assert_redacted<I, P>(&self, pred: I) -> &Self
+    where
+        I: IntoStrPredicate<P>,
+        P: predicates_core::Predicate<str>;
+}
+
+impl PathAssertRedacted for fixture::TempDir {
+    #[track_caller]
+    fn assert_redacted<I, P>(&self, pred: I) -> &Self
+    where
+        I: IntoStrPredicate<P>,
+        P: predicates_core::Predicate<str>,
+    {
+        assert(
+            self.path(),
+            RedactedPathPredicate::new(self.path(), pred.into_str()),
+        );
+        self
+    }
+}
+
+impl PathAssertRedacted for fixture::ChildPath {
+    #[track_caller]
+    fn assert_redacted<I, P>(&self, pred: I) -> &Self
+    where
+        I: IntoStrPredicate<P>,
+        P: predicates_core::Predicate<str>,
+    {
+        let root = self.root().unwrap_or(self.path());
+        assert(
+            self.path(),
+            RedactedPathPredicate::new(root, pred.into_str()),
+        );
+        self
+    }
+}
+
+/// Assert every file within a [`TempDir`] or [`ChildPath`] that matches a glob.
+///
+/// Files are found like [`PathCopy::copy_from`], following symlinks and matching `pattern`
+/// relative to the directory.  Every failing file is reported, not only the first.
+///
+/// This uses [`IntoPathPredicate`] to accept the same predicates as [`PathAssert`].
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+/// use predicates::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// temp.child("a.json").write_str("{}\n").unwrap();
+/// temp.child("sub/b.json").write_str("[]\n").unwrap();
+///
+/// // ... do something with temp ...
+///
+/// temp.assert_each("**/*.json", predicate::str::ends_with("\n"));
+/// temp.assert_each_allow_empty("**/*.tmp", predicate::path::missing());
+///
+/// temp.close().unwrap();
+/// ```
+///
+/// [`TempDir`]: super::TempDir
+/// [`ChildPath`]: super::fixture::ChildPath
+/// [`PathCopy::copy_from`]: super::fixture::PathCopy::copy_from
+pub trait PathAssertEach {
+    /// Assert every file matching `pattern`, failing if none match.
+    ///
+    /// See [`PathAssertEach`] for details.
+    ///
+    /// # Panic
+    ///
+    /// Will panic if no file matches `pattern`, or if the condition is not satisfied by any
+    /// of them
+    #[track_caller]
+    fn assert_each<I, P>(&self, pattern: &str, pred: I) -> &Self
+    where
+        I: IntoPathPredicate<P>,
+        P: predicates_core::Predicate<path::Path>;
+
+    /// Assert every file matching `pattern`, if any.
+    ///
+    /// See [`PathAssertEach`] for details.
+    ///
+    /// # Panic
+    ///
+    /// Will panic if the condition is not satisfied by any file matching `pattern`
+    #[track_caller]
+    fn assert_each_allow_empty<I, P>(&self, pattern: &str, pred: I) -> &Self
+    where
+        I: IntoPathPredicate<P>,
+        P: predicates_core::Predicate<path::Path>;
+}
+
+impl PathAssertEach for fixture::TempDir {
+    #[track_caller]
+    fn assert_each<I, P>(&self, pattern: &str, pred: I) -> &Self
+    where
+        I: IntoPathPredicate<P>,
+        P: predicates_core::Predicate<path::Path>,
+    {
+        assert_each(self.path(), pattern, pred, false);
+        self
+    }
+
+    #[track_caller]
+    fn assert_each_allow_empty<I, P>(&self, pattern: &str, pred: I) -> &Self
+    where
+        I: IntoPathPredicate<P>,
+        P: predicates_core::Predicate<path::Path>,
+    {
+        assert_each(self.path(), pattern, pred, true);
+        self
+    }
+}
+
+impl PathAssertEach for fixture::ChildPath {
+    #[track_caller]
+    fn assert_each<I, P>(&self, pattern: &str, pred: I) -> &Self
+    where
+        I: IntoPathPredicate<P>,
+        P: predicates_core::Predicate<path::Path>,
+    {
+        assert_each(self.path(), pattern, pred, false);
+        self
+    }
+
+    #[track_caller]
+    fn assert_each_allow_empty<I, P>(&self, pattern: &str, pred: I) -> &Self
+    where
+        I: IntoPathPredicate<P>,
+        P: predicates_core::Predicate<path::Path>,
+    {
+        assert_each(self.path(), pattern, pred, true);
+        self
+    }
+}
+
 #[track_caller]
 fn assert<I, P>(path: &path::Path, pred: I)
 where
@@ -157,6 +368,56 @@ where
     }
 }
 
+#[track_caller]
+fn assert_each<I, P>(path: &path::Path, pattern: &str, pred: I, allow_empty: bool)
+where
+    I: IntoPathPredicate<P>,
+    P: predicates_core::Predicate<path::Path>,
+{
+    let palette = crate::Palette::color();
+    let found = walk_root(path)
+        .map_err(|err| err.to_string())
+        .and_then(|root| dir::files(&root, &[pattern]).map(|files| (root, files)));
+    let (root, files) = match found {
+        Ok(found) => found,
+        Err(err) => panic!(
+            "Failed to search for `{pattern}`: {err}\n{:#}={:#}",
+            palette.key("path"),
+            palette.value(path.display())
+        ),
+    };
+    if files.is_empty() && !allow_empty {
+        panic!(
+            "Unexpected files, none matched `{pattern}`\n{:#}={:#}",
+            palette.key("path"),
+            palette.value(path.display())
+        );
+    }
+
+    let pred = pred.into_path();
+    let mut failures = String::new();
+    let mut failed = 0;
+    for rel in &files {
+        if let Some(case) = pred.find_case(false, &root.join(rel)) {
+            failed += 1;
+            failures.push_str(&format!(
+                "{:#}={:#}, failed {:#}\n",
+                palette.key("file"),
+                palette.value(rel),
+                case.tree()
+            ));
+        }
+    }
+    if failed != 0 {
+        panic!(
+            "Unexpected files, {failed} of {} matching `{pattern}` failed\n{failures}{:#}={:#}",
+            files.len(),
+            palette.key("path"),
+            palette.value(path.display())
+        );
+    }
+}
+
 /// Used by [`PathAssert`] to convert Self into the needed [`predicates_core::Predicate<Path>`].
 ///
 /// # Examples
@@ -200,6 +461,10 @@ where
 /// Keep `predicates` concrete Predicates out of our public API.
 /// [`predicates_core::Predicate`] used by [`IntoPathPredicate`] for bytes.
 ///
+/// This is converted from `&[u8]`, `&[u8; N]`, `Vec<u8>`, or `Cow<[u8]>`, and from
+/// `bytes::Bytes` with the `bytes` feature.  Mismatches are reported as a hexdump, like
+/// [`bytes::eq`][crate::predicate::bytes::eq].
+///
 /// # Example
 ///
 /// ```rust
@@ -211,19 +476,17 @@ where
 ///
 /// // ... do something with input_file ...
 ///
-/// input_file.assert(b"" as &[u8]); // uses BytesContentPathPredicate
+/// input_file.assert(b""); // uses BytesContentPathPredicate
+/// input_file.assert(Vec::new()); // uses BytesContentPathPredicate
 ///
 /// temp.close().unwrap();
 /// ```
 #[derive(Debug)]
-pub struct BytesContentPathPredicate(
-    predicates::path::FileContentPredicate<predicates::ord::EqPredicate<&'static [u8]>>,
-);
+pub struct BytesContentPathPredicate(BytesEqPredicate);
 
 impl BytesContentPathPredicate {
-    pub(crate) fn new(value: &'static [u8]) -> Self {
-        let pred = predicates::ord::eq(value).from_file_path();
-        Self(pred)
+    pub(crate) fn new(value: impl Into<Cow<'static, [u8]>>) -> Self {
+        Self(crate::predicate::bytes::eq(value))
     }
 }
 
@@ -262,7 +525,7 @@ impl fmt::Display for BytesContentPathPredicate {
     }
 }
 
-impl IntoPathPredicate<BytesContentPathPredicate> for &'static [u8] {
+impl IntoPathPredicate<BytesContentPathPredicate> for Vec<u8> {
     type Predicate = BytesContentPathPredicate;
 
     fn into_path(self) -> Self::Predicate {
@@ -270,13 +533,58 @@ impl IntoPathPredicate<BytesContentPathPredicate> for &'static [u8] {
     }
 }
 
+impl IntoPathPredicate<BytesContentPathPredicate> for &Vec<u8> {
+    type Predicate = BytesContentPathPredicate;
+
+    fn into_path(self) -> Self::Predicate {
+        Self::Predicate::new(self.clone())
+    }
+}
+
+impl IntoPathPredicate<BytesContentPathPredicate> for &[u8] {
+    type Predicate = BytesContentPathPredicate;
+
+    fn into_path(self) -> Self::Predicate {
+        Self::Predicate::new(self.to_vec())
+    }
+}
+
+impl<const N: usize> IntoPathPredicate<BytesContentPathPredicate> for &[u8; N] {
+    type Predicate = BytesContentPathPredicate;
+
+    fn into_path(self) -> Self::Predicate {
+        Self::Predicate::new(self.to_vec())
+    }
+}
+
+impl IntoPathPredicate<BytesContentPathPredicate> for Cow<'_, [u8]> {
+    type Predicate = BytesContentPathPredicate;
+
+    fn into_path(self) -> Self::Predicate {
+        Self::Predicate::new(self.into_owned())
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl IntoPathPredicate<BytesContentPathPredicate> for bytes::Bytes {
+    type Predicate = BytesContentPathPredicate;
+
+    fn into_path(self) -> Self::Predicate {
+        Self::Predicate::new(Vec::from(self))
+    }
+}
+
 /// Keep `predicates` concrete Predicates out of our public API.
 /// [`predicates_core::Predicate`] used by `IntoPathPredicate` for `str`.
 ///
+/// By default, the content must be identical.  Differences in line endings or whitespace can be
+/// ignored with the builder methods, like [`StrContentPathPredicate::normalize_newlines`].
+///
 /// # Example
 ///
 /// ```rust
 /// use assert_fs::prelude::*;
+/// use assert_fs::assert::StrContentPathPredicate;
 ///
 /// let temp = assert_fs::TempDir::new().unwrap();
 /// let input_file = temp.child("foo.txt");
@@ -286,19 +594,89 @@ impl IntoPathPredicate<BytesContentPathPredicate> for &'static [u8] {
 ///
 /// input_file.assert(""); // Uses StrContentPathPredicate
 ///
+/// input_file.write_str("Hello  \r\nWorld\r\n").unwrap();
+/// input_file.assert(
+///     StrContentPathPredicate::new("Hello\nWorld")
+///         .normalize_newlines()
+///         .trim_trailing_whitespace()
+///         .ignore_final_newline(),
+/// );
+///
 /// temp.close().unwrap();
 /// ```
 #[derive(Debug, Clone)]
-pub struct StrContentPathPredicate(
-    predicates::path::FileContentPredicate<
+pub struct StrContentPathPredicate {
+    pred: predicates::path::FileContentPredicate<
         predicates::str::Utf8Predicate<predicates::str::DifferencePredicate>,
     >,
-);
+    expected: String,
+    normalize_newlines: bool,
+    trim_trailing_whitespace: bool,
+    ignore_final_newline: bool,
+}
 
 impl StrContentPathPredicate {
-    pub(crate) fn new(value: String) -> Self {
-        let pred = predicates::str::diff(value).from_utf8().from_file_path();
-        Self(pred)
+    /// Compare the content of a file to `value`.
+    pub fn new(value: impl Into<String>) -> Self {
+        let expected = value.into();
+        let pred = predicates::str::diff(expected.clone())
+            .from_utf8()
+            .from_file_path();
+        Self {
+            pred,
+            expected,
+            normalize_newlines: false,
+            trim_trailing_whitespace: false,
+            ignore_final_newline: false,
+        }
+    }
+
+    /// Treat `\r\n` and `\n` as equal.
+    pub fn normalize_newlines(mut self) -> Self {
+        self.normalize_newlines = true;
+        self
+    }
+
+    /// Ignore spaces and tabs at the end of lines.
+    pub fn trim_trailing_whitespace(mut self) -> Self {
+        self.trim_trailing_whitespace = true;
+        self
+    }
+
+    /// Ignore whether the content ends with a newline.
+    pub fn ignore_final_newline(mut self) -> Self {
+        self.ignore_final_newline = true;
+        self
+    }
+
+    fn is_normalized(&self) -> bool {
+        self.normalize_newlines || self.trim_trailing_whitespace || self.ignore_final_newline
+    }
+
+    fn normalize(&self, content: &str) -> String {
+        let mut normalized = String::with_capacity(content.len());
+        for line in content.split_inclusive('\n') {
+            let mut body = line.strip_suffix('\n').unwrap_or(line);
+            let mut newline = &line[body.len()..];
+            if let Some(stripped) = body.strip_suffix('\r') {
+                body = stripped;
+                if !self.normalize_newlines {
+                    newline = &line[body.len()..];
+                }
+            }
+            if self.trim_trailing_whitespace {
+                body = body.trim_end_matches([' ', '\t']);
+            }
+            normalized.push_str(body);
+            normalized.push_str(newline);
+        }
+        if self.ignore_final_newline {
+            if let Some(stripped) = normalized.strip_suffix('\n') {
+                let len = stripped.strip_suffix('\r').unwrap_or(stripped).len();
+                normalized.truncate(len);
+            }
+        }
+        normalized
     }
 }
 
@@ -306,20 +684,41 @@ impl predicates_core::reflection::PredicateReflection for StrContentPathPredicat
     fn parameters<'a>(
         &'a self,
     ) -> Box<dyn Iterator<Item = predicates_core::reflection::Parameter<'a>> + 'a> {
-        self.0.parameters()
+        if self.is_normalized() {
+            let params = [
+                ("normalize newlines", &self.normalize_newlines),
+                ("trim trailing whitespace", &self.trim_trailing_whitespace),
+                ("ignore final newline", &self.ignore_final_newline),
+            ]
+            .into_iter()
+            .filter(|(_, enabled)| **enabled)
+            .map(|(name, enabled)| predicates_core::reflection::Parameter::new(name, enabled));
+            Box::new(params)
+        } else {
+            self.pred.parameters()
+        }
     }
 
     /// Nested `Predicate`s of the current `Predicate`.
     fn children<'a>(
         &'a self,
     ) -> Box<dyn Iterator<Item = predicates_core::reflection::Child<'a>> + 'a> {
-        self.0.children()
+        if self.is_normalized() {
+            Box::new(std::iter::empty())
+        } else {
+            self.pred.children()
+        }
     }
 }
 
 impl predicates_core::Predicate<path::Path> for StrContentPathPredicate {
     fn eval(&self, item: &path::Path) -> bool {
-        self.0.eval(item)
+        if !self.is_normalized() {
+            return self.pred.eval(item);
+        }
+        std::fs::read_to_string(item)
+            .map(|content| self.normalize(&content) == self.normalize(&self.expected))
+            .unwrap_or(false)
     }
 
     fn find_case<'a>(
@@ -327,13 +726,65 @@ impl predicates_core::Predicate<path::Path> for StrContentPathPredicate {
         expected: bool,
         variable: &path::Path,
     ) -> Option<predicates_core::reflection::Case<'a>> {
-        self.0.find_case(expected, variable)
+        if !self.is_normalized() {
+            return self.pred.find_case(expected, variable);
+        }
+        let content = match std::fs::read_to_string(variable) {
+            Ok(content) => content,
+            Err(err) => return crate::predicate::error_case(self, expected, err),
+        };
+        let result = self.normalize(&content) == self.normalize(&self.expected);
+        if result != expected {
+            return None;
+        }
+        let mut case = predicates_core::reflection::Case::new(Some(self), result);
+        if !result {
+            // Show the raw content, with invisible characters escaped, to see what normalizing
+            // didn't cover.
+            case = case.add_product(predicates_core::reflection::Product::new(
+                "diff",
+                crate::predicate::diff::unified(&escape(&self.expected), &escape(&content)),
+            ));
+        }
+        Some(case)
     }
 }
 
+/// Make `\r`, tabs, trailing spaces, and a missing final newline visible.
+fn escape(content: &str) -> String {
+    let mut escaped = String::with_capacity(content.len());
+    for line in content.split_inclusive('\n') {
+        let body = line.strip_suffix('\n').unwrap_or(line);
+        let (body, cr) = match body.strip_suffix('\r') {
+            Some(body) => (body, "\\r"),
+            None => (body, ""),
+        };
+        let trimmed = body.trim_end_matches(' ');
+        escaped.push_str(&trimmed.replace('\r', "\\r").replace('\t', "\\t"));
+        escaped.push_str(&"·".repeat(body.len() - trimmed.len()));
+        escaped.push_str(cr);
+        if line.ends_with('\n') {
+            escaped.push('\n');
+        } else {
+            escaped.push_str("\\ No newline at end of file\n");
+        }
+    }
+    escaped
+}
+
 impl fmt::Display for StrContentPathPredicate {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.0.fmt(f)
+        if self.is_normalized() {
+            let palette = crate::Palette::color();
+            write!(
+                f,
+                "{:#} {:#}",
+                palette.key("normalized var =="),
+                palette.value(format!("{:?}", self.expected))
+            )
+        } else {
+            self.pred.fmt(f)
+        }
     }
 }
 
@@ -361,6 +812,30 @@ impl IntoPathPredicate<StrContentPathPredicate> for &String {
     }
 }
 
+impl IntoPathPredicate<FileEqPredicate> for &path::Path {
+    type Predicate = FileEqPredicate;
+
+    fn into_path(self) -> Self::Predicate {
+        crate::predicate::file::eq_file(self)
+    }
+}
+
+impl IntoPathPredicate<FileEqPredicate> for path::PathBuf {
+    type Predicate = FileEqPredicate;
+
+    fn into_path(self) -> Self::Predicate {
+        crate::predicate::file::eq_file(self)
+    }
+}
+
+impl IntoPathPredicate<FileEqPredicate> for &path::PathBuf {
+    type Predicate = FileEqPredicate;
+
+    fn into_path(self) -> Self::Predicate {
+        crate::predicate::file::eq_file(self)
+    }
+}
+
 /// Keep `predicates` concrete Predicates out of our public API.
 /// [`predicates_core::Predicate`] used by `IntoPathPredicate` for `str` predicates.
 ///
@@ -450,6 +925,154 @@ where
     }
 }
 
+/// Used by [`PathAssertRedacted`] to convert Self into the needed
+/// [`predicates_core::Predicate<str>`].
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+/// use predicates::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// let input_file = temp.child("foo.txt");
+/// input_file.touch().unwrap();
+///
+/// // ... do something with input_file ...
+///
+/// input_file.assert_redacted(predicate::str::is_empty()); // Uses IntoStrPredicate
+///
+/// temp.close().unwrap();
+/// ```
+pub trait IntoStrPredicate<P>
+where
+    P: predicates_core::Predicate<str>,
+{
+    /// The type of the predicate being returned.
+    type Predicate;
+
+    /// Convert to a predicate for testing text.
+    fn into_str(self) -> P;
+}
+
+impl<P> IntoStrPredicate<P> for P
+where
+    P: predicates_core::Predicate<str>,
+{
+    type Predicate = P;
+
+    fn into_str(self) -> Self::Predicate {
+        self
+    }
+}
+
+impl IntoStrPredicate<WildcardPredicate> for String {
+    type Predicate = WildcardPredicate;
+
+    fn into_str(self) -> Self::Predicate {
+        wildcard_eq(self)
+    }
+}
+
+impl IntoStrPredicate<WildcardPredicate> for &str {
+    type Predicate = WildcardPredicate;
+
+    fn into_str(self) -> Self::Predicate {
+        wildcard_eq(self)
+    }
+}
+
+impl IntoStrPredicate<WildcardPredicate> for &String {
+    type Predicate = WildcardPredicate;
+
+    fn into_str(self) -> Self::Predicate {
+        wildcard_eq(self.as_str())
+    }
+}
+
+/// Applies a `Predicate<str>` to the content of a file after replacing the fixture root.
+#[derive(Debug)]
+struct RedactedPathPredicate<P> {
+    redactions: WildcardPredicate,
+    pred: P,
+}
+
+impl<P> RedactedPathPredicate<P>
+where
+    P: predicates_core::Predicate<str>,
+{
+    fn new(root: &path::Path, pred: P) -> Self {
+        let mut redactions = wildcard_eq("").redact("[ROOT]", root);
+        if let Ok(canonical) = root.canonicalize() {
+            if canonical != root {
+                redactions = redactions.redact("[ROOT]", canonical);
+            }
+        }
+        Self { redactions, pred }
+    }
+
+    fn read(&self, path: &path::Path) -> Result<String, String> {
+        let content = std::fs::read_to_string(path).map_err(|err| err.to_string())?;
+        Ok(self.redactions.redacted(&content))
+    }
+}
+
+impl<P> predicates_core::reflection::PredicateReflection for RedactedPathPredicate<P>
+where
+    P: predicates_core::Predicate<str>,
+{
+    fn children<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = predicates_core::reflection::Child<'a>> + 'a> {
+        let params = vec![predicates_core::reflection::Child::new(
+            "predicate",
+            &self.pred,
+        )];
+        Box::new(params.into_iter())
+    }
+}
+
+impl<P> predicates_core::Predicate<path::Path> for RedactedPathPredicate<P>
+where
+    P: predicates_core::Predicate<str>,
+{
+    fn eval(&self, item: &path::Path) -> bool {
+        self.read(item)
+            .map(|content| self.pred.eval(&content))
+            .unwrap_or(false)
+    }
+
+    fn find_case<'a>(
+        &'a self,
+        expected: bool,
+        variable: &path::Path,
+    ) -> Option<predicates_core::reflection::Case<'a>> {
+        let content = match self.read(variable) {
+            Ok(content) => content,
+            Err(err) => return crate::predicate::error_case(self, expected, err),
+        };
+        let child = self.pred.find_case(expected, &content)?;
+        Some(
+            predicates_core::reflection::Case::new(Some(self), expected)
+                .add_product(predicates_core::reflection::Product::new(
+                    "redacted content",
+                    content,
+                ))
+                .add_child(child),
+        )
+    }
+}
+
+impl<P> fmt::Display for RedactedPathPredicate<P>
+where
+    P: predicates_core::Predicate<str>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let palette = crate::Palette::color();
+        write!(f, "{:#}", palette.key("redacted content"))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -482,6 +1105,133 @@ mod test {
         assert!(case.is_none());
     }
 
+    #[test]
+    fn into_path_from_owned_bytes() {
+        let path = path::Path::new("tests/fixture/hello.txt");
+        let expected = b"hello\n";
+        assert!(convert_path(expected).find_case(false, path).is_none());
+        assert!(
+            convert_path(expected.to_vec())
+                .find_case(false, path)
+                .is_none()
+        );
+        assert!(
+            convert_path(Cow::Borrowed(expected.as_slice()))
+                .find_case(false, path)
+                .is_none()
+        );
+        #[cfg(feature = "bytes")]
+        assert!(
+            convert_path(bytes::Bytes::from_static(expected))
+                .find_case(false, path)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn into_path_from_path() {
+        let pred = convert_path(path::Path::new("tests/fixture/hello.txt"));
+        let case = pred.find_case(false, path::Path::new("tests/fixture/hello.txt"));
+        println!("Failing case: {case:?}");
+        assert!(case.is_none());
+
+        let pred = convert_path(path::PathBuf::from("tests/fixture/hello.txt"));
+        let case = pred.find_case(true, path::Path::new("Cargo.toml"));
+        println!("Failing case: {case:?}");
+        assert!(case.is_none());
+    }
+
+    #[test]
+    fn each() {
+        use crate::fixture::FileWriteStr;
+        use crate::fixture::PathChild;
+
+        let temp = fixture::TempDir::new().unwrap();
+        temp.child("a.json").write_str("{}").unwrap();
+        temp.child("sub/b.json").write_str("[]").unwrap();
+        temp.child("sub/c.json").write_str("[]").unwrap();
+        temp.child("d.txt").write_str("").unwrap();
+
+        temp.child("sub").assert_each("*.json", "[]");
+        temp.assert_each_allow_empty("*.md", "");
+        let err = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            temp.assert_each("**/*.json", "{}");
+        }))
+        .unwrap_err();
+        let message = err.downcast_ref::<String>().unwrap();
+        assert!(
+            message.contains("2 of 3 matching `**/*.json` failed"),
+            "{message}"
+        );
+        assert!(message.contains("sub/b.json"), "{message}");
+        assert!(message.contains("sub/c.json"), "{message}");
+    }
+
+    #[test]
+    #[should_panic(expected = "none matched `**/*.md`")]
+    fn each_requires_matches() {
+        let temp = fixture::TempDir::new().unwrap();
+        temp.assert_each("**/*.md", "");
+    }
+
+    #[test]
+    fn redacted() {
+        use crate::fixture::PathChild;
+
+        let temp = fixture::TempDir::new().unwrap();
+        let root = temp.path().to_owned();
+        let canonical = root.canonicalize().unwrap();
+        let file = temp.child("sub/foo.txt");
+        std::fs::create_dir_all(file.parent().unwrap()).unwrap();
+        std::fs::write(
+            file.path(),
+            format!("{}\n{}\n", root.display(), canonical.display()),
+        )
+        .unwrap();
+
+        file.assert_redacted("[ROOT]\n[ROOT]\n");
+        temp.child("sub")
+            .child("foo.txt")
+            .assert_redacted("[ROOT]\n[ROOT]\n");
+        let pred = RedactedPathPredicate::new(&root, wildcard_eq("[ROOT]\n"));
+        let case = pred.find_case(false, file.path()).unwrap();
+        assert_eq!(
+            case.products().next().unwrap().value().to_string(),
+            "[ROOT]\n[ROOT]\n"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "redacted content")]
+    fn redacted_temp_dir() {
+        let temp = fixture::TempDir::new().unwrap();
+        temp.assert_redacted("");
+    }
+
+    #[test]
+    fn str_content_normalized() {
+        let pred = StrContentPathPredicate::new("a\nb\n");
+        assert_eq!(pred.normalize("a\r\nb  \n"), "a\r\nb  \n");
+
+        let pred = pred.normalize_newlines();
+        assert_eq!(pred.normalize("a\r\nb  \n"), "a\nb  \n");
+
+        let pred = pred.trim_trailing_whitespace();
+        assert_eq!(pred.normalize("a\t\r\nb  \n"), "a\nb\n");
+
+        let pred = StrContentPathPredicate::new("").ignore_final_newline();
+        assert_eq!(pred.normalize("a\r\nb\r\n"), "a\r\nb");
+        assert_eq!(pred.normalize("a\nb"), "a\nb");
+    }
+
+    #[test]
+    fn str_content_escaped() {
+        assert_eq!(
+            escape("a\t \r\nb  "),
+            "a\\t·\\r\nb··\\ No newline at end of file\n"
+        );
+    }
+
     #[test]
     fn into_path_from_str() {
         let pred = convert_path("hello\n");
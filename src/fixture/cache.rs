@@ -0,0 +1,451 @@
+use std::fs;
+use std::io;
+use std::path;
+use std::sync::Mutex;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use super::ChildPath;
+use super::CopyOptions;
+use super::TempDir;
+use super::copy::copy_files;
+use super::errors::FixtureError;
+use super::errors::FixtureKind;
+use super::errors::ResultChainExt;
+
+/// Directory, under the cache, holding the fixtures built once per test binary.
+const LOCAL_DIR: &str = ".local";
+/// Time after which an unused fixture is assumed to be left over from an old test binary or
+/// version.
+const STALE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// A pristine fixture that is built once and then copied into a [`TempDir`] for each test.
+///
+/// The fixture is identified by a `name` and `version`; bump the `version` whenever `build`
+/// changes so the fixture gets rebuilt.  Both are used as directory names.
+///
+/// By default, the fixture is built once per test binary, and reused across runs until the test
+/// binary is rebuilt.  With [`CachedFixture::shared`], it is instead built once and reused across
+/// test binaries too.  Either way, a lock file ensures only one process builds it.
+///
+/// The fixture is stored in `assert_fs-cache` next to the test binary's `deps` directory (e.g.
+/// `target/debug/assert_fs-cache`).  Fixtures for old test binaries and versions can't tell when
+/// they are no longer needed, so they are removed by later builds once unused for a day.
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+/// use assert_fs::fixture::CachedFixture;
+/// use assert_fs::fixture::ChildPath;
+/// use assert_fs::fixture::FixtureError;
+///
+/// fn build(root: &ChildPath) -> Result<(), FixtureError> {
+///     // ... something expensive ...
+///     root.child("generated.txt").write_str("Hello")
+/// }
+///
+/// static REPO: CachedFixture = CachedFixture::new("repo", "1", build);
+///
+/// let temp = REPO.copy().unwrap();
+/// temp.child("generated.txt").assert("Hello");
+/// temp.close().unwrap();
+/// ```
+#[derive(Debug)]
+pub struct CachedFixture {
+    name: &'static str,
+    version: &'static str,
+    build: fn(&ChildPath) -> Result<(), FixtureError>,
+    shared: bool,
+    root: Option<path::PathBuf>,
+    lock_timeout: Duration,
+    pristine: Mutex<Option<path::PathBuf>>,
+}
+
+impl CachedFixture {
+    /// Describe a fixture populated by `build`.
+    pub const fn new(
+        name: &'static str,
+        version: &'static str,
+        build: fn(&ChildPath) -> Result<(), FixtureError>,
+    ) -> Self {
+        Self {
+            name,
+            version,
+            build,
+            shared: false,
+            root: None,
+            lock_timeout: Duration::from_secs(10 * 60),
+            pristine: Mutex::new(None),
+        }
+    }
+
+    /// Build the fixture once across test binaries, rather than once per test binary.
+    pub const fn shared(mut self) -> Self {
+        self.shared = true;
+        self
+    }
+
+    /// Like [`CachedFixture::shared`], storing the fixture in `root`.
+    pub fn shared_in(mut self, root: impl Into<path::PathBuf>) -> Self {
+        self.shared = true;
+        self.root = Some(root.into());
+        self
+    }
+
+    /// Fail with [`FixtureKind::Lock`] once the lock held by another process building the
+    /// fixture hasn't been refreshed for longer than `timeout`.
+    ///
+    /// The process building the fixture refreshes its lock every quarter of `timeout`, so this is
+    /// how a lock left behind by a process killed while building the fixture is detected; the
+    /// `.lock` file in the fixture's directory then needs to be removed.
+    ///
+    /// Defaults to 10 minutes.
+    pub const fn lock_timeout(mut self, timeout: Duration) -> Self {
+        self.lock_timeout = timeout;
+        self
+    }
+
+    /// Copy the fixture into a new [`TempDir`], building it first if needed.
+    pub fn copy(&self) -> Result<TempDir, FixtureError> {
+        self.copy_with(&CopyOptions::new())
+    }
+
+    /// Copy the fixture into a new [`TempDir`], building it first if needed.
+    ///
+    /// See [`CopyOptions`] for how to customize the copy, like hard-linking files for read-only
+    /// fixtures.
+    pub fn copy_with(&self, options: &CopyOptions) -> Result<TempDir, FixtureError> {
+        let source = {
+            let mut pristine = self.pristine.lock().unwrap_or_else(|e| e.into_inner());
+            if pristine.is_none() {
+                *pristine = Some(self.init()?);
+            }
+            pristine.as_ref().expect("initialized").clone()
+        };
+        let temp = TempDir::new()?;
+        copy_files(temp.path(), &source, &["**"], options)?;
+        Ok(temp)
+    }
+
+    fn init(&self) -> Result<path::PathBuf, FixtureError> {
+        let root = match &self.root {
+            Some(root) => root.clone(),
+            None => default_root().chain(FixtureError::new(FixtureKind::CreateDir))?,
+        };
+        if self.shared {
+            self.init_shared(&root.join(self.name))
+        } else {
+            self.init_local(&root.join(LOCAL_DIR))
+        }
+    }
+
+    fn init_local(&self, dir: &path::Path) -> Result<path::PathBuf, FixtureError> {
+        fs::create_dir_all(dir).chain(FixtureError::new(FixtureKind::CreateDir).with_path(dir))?;
+        for entry in
+            fs::read_dir(dir).chain(FixtureError::new(FixtureKind::Cleanup).with_path(dir))?
+        {
+            let entry = entry.chain(FixtureError::new(FixtureKind::Cleanup).with_path(dir))?;
+            if age(&entry.path()).is_some_and(|age| STALE < age) {
+                // Best effort as another process might be removing it too
+                let _ = fs::remove_dir_all(entry.path());
+            }
+        }
+
+        let binary = binary_key().chain(FixtureError::new(FixtureKind::Metadata))?;
+        let dir = dir.join(format!("{}-{}", self.name, binary));
+        let pristine = self.init_shared(&dir)?;
+        touch(&dir);
+        Ok(pristine)
+    }
+
+    fn init_shared(&self, dir: &path::Path) -> Result<path::PathBuf, FixtureError> {
+        let pristine = dir.join(self.version);
+        if pristine.exists() {
+            touch(&pristine);
+            return Ok(pristine);
+        }
+
+        fs::create_dir_all(dir).chain(FixtureError::new(FixtureKind::CreateDir).with_path(dir))?;
+        let lock_path = dir.join(".lock");
+        let _lock = loop {
+            match Lock::acquire(&lock_path, self.lock_timeout / 4) {
+                Ok(lock) => break lock,
+                Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {
+                    // Another process is building it, unless it was killed while doing so
+                    if let Some(held) = age(&lock_path).filter(|held| self.lock_timeout < *held) {
+                        let err = io::Error::new(
+                            io::ErrorKind::TimedOut,
+                            format!(
+                                "lock not refreshed for {}s, remove it if no other process is \
+                                 building the fixture",
+                                held.as_secs()
+                            ),
+                        );
+                        return Err(err)
+                            .chain(FixtureError::new(FixtureKind::Lock).with_path(lock_path));
+                    }
+                    thread::sleep(Duration::from_millis(100));
+                    if pristine.exists() {
+                        return Ok(pristine);
+                    }
+                }
+                Err(err) => {
+                    return Err(err)
+                        .chain(FixtureError::new(FixtureKind::Lock).with_path(lock_path));
+                }
+            }
+        };
+        if pristine.exists() {
+            return Ok(pristine);
+        }
+
+        for entry in
+            fs::read_dir(dir).chain(FixtureError::new(FixtureKind::Cleanup).with_path(dir))?
+        {
+            let entry = entry.chain(FixtureError::new(FixtureKind::Cleanup).with_path(dir))?;
+            let name = entry.file_name();
+            if name == ".lock" {
+                continue;
+            }
+            // Other versions might still be copied from by processes that built them earlier,
+            // unlike builds that were left behind while holding the lock.
+            let abandoned = name.to_string_lossy().starts_with(".build-");
+            if abandoned || age(&entry.path()).is_some_and(|age| STALE < age) {
+                // Best effort as the entry is merely taking up space
+                let _ = fs::remove_dir_all(entry.path());
+            }
+        }
+
+        // Build under a temporary name and rename it into place so the fixture is never seen
+        // half-built, even if the build fails or the process is killed.
+        let staging = tempfile::TempDir::with_prefix_in(".build-", dir)
+            .chain(FixtureError::new(FixtureKind::CreateDir).with_path(dir))?;
+        (self.build)(&ChildPath::new(staging.path()))?;
+        let staging_path = staging.keep();
+        fs::rename(&staging_path, &pristine).chain(
+            FixtureError::new(FixtureKind::Rename)
+                .with_path(&pristine)
+                .with_source_path(staging_path),
+        )?;
+        Ok(pristine)
+    }
+}
+
+fn default_root() -> io::Result<path::PathBuf> {
+    let exe = std::env::current_exe()?;
+    let mut dir = exe.parent().unwrap_or(&exe);
+    if dir.file_name() == Some("deps".as_ref()) {
+        dir = dir.parent().unwrap_or(dir);
+    }
+    Ok(dir.join("assert_fs-cache"))
+}
+
+/// Identify the running test binary, so its fixtures get rebuilt along with it.
+fn binary_key() -> io::Result<String> {
+    let exe = std::env::current_exe()?;
+    let modified = fs::metadata(&exe)?.modified()?;
+    let modified = modified
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default();
+    let stem = exe.file_stem().unwrap_or_default().to_string_lossy();
+    Ok(format!("{}-{}", stem, modified.as_secs()))
+}
+
+/// Mark `path` as recently used, so it isn't removed as stale.
+fn touch(path: &path::Path) {
+    // Best effort as failing to do so only risks rebuilding the fixture
+    let _ = filetime::set_file_mtime(path, filetime::FileTime::now());
+}
+
+/// Time since `path` was last modified, if known.
+fn age(path: &path::Path) -> Option<Duration> {
+    let modified = fs::metadata(path).and_then(|m| m.modified()).ok()?;
+    modified.elapsed().ok()
+}
+
+#[derive(Debug)]
+struct Lock {
+    path: path::PathBuf,
+    heartbeat: Option<(mpsc::Sender<()>, thread::JoinHandle<()>)>,
+}
+
+impl Lock {
+    /// Create the lock file at `path`, refreshing it every `interval` until dropped so waiting
+    /// processes can tell it apart from one left behind by a killed process.
+    fn acquire(path: &path::Path, interval: Duration) -> io::Result<Self> {
+        fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path)?;
+        let (stop, stopped) = mpsc::channel::<()>();
+        let refreshed = path.to_owned();
+        let heartbeat = thread::spawn(move || {
+            while let Err(mpsc::RecvTimeoutError::Timeout) = stopped.recv_timeout(interval) {
+                touch(&refreshed);
+            }
+        });
+        Ok(Self {
+            path: path.to_owned(),
+            heartbeat: Some((stop, heartbeat)),
+        })
+    }
+}
+
+impl Drop for Lock {
+    fn drop(&mut self) {
+        if let Some((stop, heartbeat)) = self.heartbeat.take() {
+            drop(stop);
+            let _ = heartbeat.join();
+        }
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+
+    use crate::fixture::FileWriteStr;
+    use crate::fixture::PathChild;
+
+    fn assert_copies(fixture: &CachedFixture) {
+        let temp = fixture.copy().unwrap();
+        let content = fs::read_to_string(temp.child("sub/hello.txt").path()).unwrap();
+        assert_eq!(content, "hello");
+    }
+
+    #[test]
+    fn local() {
+        static BUILDS: AtomicUsize = AtomicUsize::new(0);
+        let root = TempDir::new().unwrap();
+        let mut fixture = CachedFixture::new("local", "1", |root| {
+            BUILDS.fetch_add(1, Ordering::SeqCst);
+            root.child("sub/hello.txt").write_str("hello")
+        });
+        fixture.root = Some(root.path().to_owned());
+        assert_copies(&fixture);
+        assert_copies(&fixture);
+        assert_eq!(BUILDS.load(Ordering::SeqCst), 1);
+
+        let pristine = fixture.pristine.lock().unwrap().clone().unwrap();
+        let binary = root
+            .child(LOCAL_DIR)
+            .child(format!("local-{}", binary_key().unwrap()));
+        assert_eq!(pristine, binary.child("1").path());
+        assert!(!binary.child(".lock").exists());
+    }
+
+    #[test]
+    fn local_removes_stale() {
+        let root = TempDir::new().unwrap();
+        let stale = root.child("old-1");
+        stale.child("1/sub/hello.txt").write_str("hello").unwrap();
+        let old = filetime::FileTime::from_unix_time(1_000_000_000, 0);
+        filetime::set_file_mtime(stale.path(), old).unwrap();
+        let recent = root.child("other-1");
+        recent.child("1/sub/hello.txt").write_str("hello").unwrap();
+
+        let fixture = CachedFixture::new("local", "1", |root| {
+            root.child("sub/hello.txt").write_str("hello")
+        });
+        let pristine = fixture.init_local(root.path()).unwrap();
+        assert!(pristine.join("sub/hello.txt").exists());
+        assert!(!stale.exists());
+        assert!(recent.exists());
+    }
+
+    #[test]
+    fn shared() {
+        static BUILDS: AtomicUsize = AtomicUsize::new(0);
+        fn build(root: &ChildPath) -> Result<(), FixtureError> {
+            BUILDS.fetch_add(1, Ordering::SeqCst);
+            root.child("sub/hello.txt").write_str("hello")
+        }
+
+        let root = TempDir::new().unwrap();
+        let first = CachedFixture::new("shared", "1", build).shared_in(root.path());
+        let second = CachedFixture::new("shared", "1", build).shared_in(root.path());
+        for fixture in [&first, &first, &second] {
+            assert_copies(fixture);
+        }
+        assert_eq!(BUILDS.load(Ordering::SeqCst), 1);
+        assert!(root.child("shared/1").exists());
+        assert!(!root.child("shared/.lock").exists());
+    }
+
+    #[test]
+    fn shared_version() {
+        static BUILDS: AtomicUsize = AtomicUsize::new(0);
+        fn build(root: &ChildPath) -> Result<(), FixtureError> {
+            BUILDS.fetch_add(1, Ordering::SeqCst);
+            root.child("sub/hello.txt").write_str("hello")
+        }
+
+        let root = TempDir::new().unwrap();
+        let old = filetime::FileTime::from_unix_time(1_000_000_000, 0);
+        root.child("shared/0/sub/hello.txt")
+            .write_str("hello")
+            .unwrap();
+        filetime::set_file_mtime(root.child("shared/0").path(), old).unwrap();
+        root.child("shared/.build-abandoned/sub/hello.txt")
+            .write_str("hello")
+            .unwrap();
+        assert_copies(&CachedFixture::new("shared", "1", build).shared_in(root.path()));
+        assert_copies(&CachedFixture::new("shared", "2", build).shared_in(root.path()));
+        assert_eq!(BUILDS.load(Ordering::SeqCst), 2);
+        assert!(!root.child("shared/0").exists());
+        assert!(!root.child("shared/.build-abandoned").exists());
+        assert!(root.child("shared/1").exists());
+        assert!(root.child("shared/2").exists());
+        assert!(!root.child("shared/.lock").exists());
+    }
+
+    #[test]
+    fn shared_slow_build() {
+        static BUILDS: AtomicUsize = AtomicUsize::new(0);
+        fn build(root: &ChildPath) -> Result<(), FixtureError> {
+            BUILDS.fetch_add(1, Ordering::SeqCst);
+            thread::sleep(Duration::from_millis(800));
+            root.child("sub/hello.txt").write_str("hello")
+        }
+
+        let root = TempDir::new().unwrap();
+        let fixtures: Vec<_> = (0..2)
+            .map(|_| {
+                CachedFixture::new("shared", "1", build)
+                    .shared_in(root.path())
+                    .lock_timeout(Duration::from_millis(200))
+            })
+            .collect();
+        thread::scope(|scope| {
+            for fixture in &fixtures {
+                scope.spawn(|| assert_copies(fixture));
+            }
+        });
+        assert_eq!(BUILDS.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn shared_stale_lock() {
+        let root = TempDir::new().unwrap();
+        let lock = root.child("shared/.lock");
+        lock.write_str("").unwrap();
+        let old = filetime::FileTime::from_unix_time(1_000_000_000, 0);
+        filetime::set_file_mtime(lock.path(), old).unwrap();
+
+        let fixture = CachedFixture::new("shared", "1", |root| {
+            root.child("sub/hello.txt").write_str("hello")
+        })
+        .shared_in(root.path());
+        let err = fixture.copy().unwrap_err();
+        assert_eq!(err.kind(), FixtureKind::Lock);
+        assert_eq!(err.path(), Some(lock.path()));
+        assert_eq!(err.io_error_kind(), Some(io::ErrorKind::TimedOut));
+        assert!(lock.exists());
+    }
+}
@@ -36,7 +36,10 @@ impl PathChild for super::TempDir {
     where
         P: AsRef<path::Path>,
     {
-        ChildPath::new(self.path().join(path.as_ref()))
+        ChildPath {
+            path: self.path().join(path.as_ref()),
+            root: Some(self.path().to_owned()),
+        }
     }
 }
 
@@ -45,7 +48,10 @@ impl PathChild for ChildPath {
     where
         P: AsRef<path::Path>,
     {
-        Self::new(self.path().join(path.as_ref()))
+        Self {
+            path: self.path().join(path.as_ref()),
+            root: self.root.clone(),
+        }
     }
 }
 
@@ -70,6 +76,7 @@ impl PathChild for ChildPath {
 ///
 pub struct ChildPath {
     path: path::PathBuf,
+    root: Option<path::PathBuf>,
 }
 
 impl ChildPath {
@@ -81,13 +88,21 @@ impl ChildPath {
     where
         P: Into<path::PathBuf>,
     {
-        Self { path: path.into() }
+        Self {
+            path: path.into(),
+            root: None,
+        }
     }
 
     /// Access the path.
     pub fn path(&self) -> &path::Path {
         &self.path
     }
+
+    /// The [`TempDir`][crate::TempDir] this path was created from, if any.
+    pub(crate) fn root(&self) -> Option<&path::Path> {
+        self.root.as_deref()
+    }
 }
 
 impl AsRef<path::Path> for ChildPath {
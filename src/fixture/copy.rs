@@ -0,0 +1,588 @@
+use std::fs;
+use std::path;
+use std::sync::Mutex;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use super::errors::ChainError;
+use super::errors::FixtureError;
+use super::errors::FixtureKind;
+use super::errors::ResultChainExt;
+
+/// Customize how [`PathCopyWith::copy_from_with`][super::PathCopyWith::copy_from_with] copies
+/// files.
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+/// use assert_fs::fixture::CopyOptions;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// let options = CopyOptions::new().preserve_metadata(true);
+/// let report = temp.copy_from_with(".", &["*.rs"], &options).unwrap();
+/// println!("{} files copied", report.files());
+/// temp.close().unwrap();
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct CopyOptions {
+    preserve_metadata: bool,
+    strategy: CopyStrategy,
+    jobs: usize,
+    require_matches: bool,
+}
+
+impl CopyOptions {
+    /// Copy with the same behavior as [`PathCopy::copy_from`][super::PathCopy::copy_from].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Preserve the permissions and modification time of files and directories.
+    ///
+    /// By default, only the permissions of files are kept, like with [`std::fs::copy`], and
+    /// directories are created with the default permissions.
+    ///
+    /// Note: read-only directories are copied as read-only, so their permissions will need to be
+    /// relaxed before the fixture can be cleaned up.
+    pub fn preserve_metadata(mut self, yes: bool) -> Self {
+        self.preserve_metadata = yes;
+        self
+    }
+
+    /// Select how file content is copied.
+    ///
+    /// Defaults to [`CopyStrategy::Reflink`].
+    pub fn strategy(mut self, strategy: CopyStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Copy files on up to `jobs` threads.
+    ///
+    /// The source tree is still walked, and directories created, on the calling thread.  The
+    /// result, including which error is reported, is the same as when copying sequentially.
+    ///
+    /// Defaults to `1`.
+    pub fn jobs(mut self, jobs: usize) -> Self {
+        self.jobs = jobs;
+        self
+    }
+
+    /// Fail with [`FixtureKind::UnmatchedPattern`] if any pattern matches no entries.
+    ///
+    /// This catches typos in patterns that would otherwise silently copy nothing.  Exclusions
+    /// (patterns starting with `!`) are not checked.  Nothing is copied when the check fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    /// use assert_fs::fixture::CopyOptions;
+    /// use assert_fs::fixture::FixtureKind;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let options = CopyOptions::new().require_matches(true);
+    /// let err = temp.copy_from_with(".", &["*.rs", "*.rss"], &options).unwrap_err();
+    /// assert_eq!(err.kind(), FixtureKind::UnmatchedPattern);
+    /// temp.close().unwrap();
+    /// ```
+    pub fn require_matches(mut self, yes: bool) -> Self {
+        self.require_matches = yes;
+        self
+    }
+}
+
+/// How [`CopyOptions`] copies file content.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CopyStrategy {
+    /// Always copy the content of files.
+    Copy,
+    /// Clone files when the filesystem supports copy-on-write, falling back to copying the
+    /// content.
+    ///
+    /// Clones share storage with the source until either side is modified, so they are
+    /// indistinguishable from regular copies.
+    #[default]
+    Reflink,
+    /// Hard-link files, falling back to copying the content, e.g. when crossing filesystems.
+    ///
+    /// Hard-links share their content and metadata with the source, so this is only appropriate
+    /// for fixtures that will not be modified.
+    HardLink,
+}
+
+/// Summary of how [`PathCopyWith::copy_from_with`][super::PathCopyWith::copy_from_with] copied
+/// files.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CopyReport {
+    copied: usize,
+    reflinked: usize,
+    hard_linked: usize,
+}
+
+impl CopyReport {
+    /// Number of files put in place, regardless of how.
+    pub fn files(&self) -> usize {
+        self.copied + self.reflinked + self.hard_linked
+    }
+
+    /// Number of files whose content was copied.
+    pub fn copied(&self) -> usize {
+        self.copied
+    }
+
+    /// Number of files cloned with [`CopyStrategy::Reflink`].
+    pub fn reflinked(&self) -> usize {
+        self.reflinked
+    }
+
+    /// Number of files linked with [`CopyStrategy::HardLink`].
+    pub fn hard_linked(&self) -> usize {
+        self.hard_linked
+    }
+
+    fn record(&mut self, used: CopyStrategy) {
+        match used {
+            CopyStrategy::Copy => self.copied += 1,
+            CopyStrategy::Reflink => self.reflinked += 1,
+            CopyStrategy::HardLink => self.hard_linked += 1,
+        }
+    }
+}
+
+pub(crate) fn copy_files<S>(
+    target: &path::Path,
+    source: &path::Path,
+    patterns: &[S],
+    options: &CopyOptions,
+) -> Result<CopyReport, FixtureError>
+where
+    S: AsRef<str>,
+{
+    let source = walk_root(source).chain(FixtureError::new(FixtureKind::Walk).with_path(source))?;
+    if options.require_matches {
+        check_matches(&source, patterns)?;
+    }
+    let mut files = Vec::new();
+    let mut dirs = Vec::new();
+    for entry in globwalk::GlobWalkerBuilder::from_patterns(&source, patterns)
+        .follow_links(true)
+        .build()
+        .chain(FixtureError::new(FixtureKind::Walk).with_path(&source))?
+    {
+        let entry = entry.map_err(|e| walk_error(&source, e))?;
+        let rel = entry
+            .path()
+            .strip_prefix(&source)
+            .expect("entries to be under `source`");
+        if rel.as_os_str().is_empty() {
+            // The root is `target`, which keeps its own metadata so it can still be cleaned up
+            // when `source` is read-only.
+            continue;
+        }
+        let target_path = target.join(rel);
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&target_path)
+                .chain(FixtureError::new(FixtureKind::CreateDir).with_path(&target_path))?;
+            if options.preserve_metadata {
+                let metadata = entry.metadata().map_err(|e| walk_error(&source, e))?;
+                dirs.push((target_path, metadata));
+            }
+        } else if entry.file_type().is_file() {
+            let parent = target_path.parent().expect("at least `target` exists");
+            fs::create_dir_all(parent)
+                .chain(FixtureError::new(FixtureKind::CreateDir).with_path(parent))?;
+            files.push((entry.into_path(), target_path));
+        }
+    }
+
+    let jobs = options.jobs.clamp(1, files.len().max(1));
+    let report = if jobs == 1 {
+        let mut report = CopyReport::default();
+        for (source_path, target_path) in &files {
+            let used = copy_entry(source_path, target_path, options)?;
+            report.record(used);
+        }
+        report
+    } else {
+        copy_parallel(&files, options, jobs)?
+    };
+
+    // Populating a directory updates its mtime and a read-only directory can't be populated, so
+    // apply directory metadata only once all of the content is in place, innermost first.
+    for (target_path, metadata) in dirs.iter().rev() {
+        fs::set_permissions(target_path, metadata.permissions())
+            .chain(FixtureError::new(FixtureKind::Permissions).with_path(target_path))?;
+        set_mtime(target_path, metadata)
+            .chain(FixtureError::new(FixtureKind::Metadata).with_path(target_path))?;
+    }
+
+    Ok(report)
+}
+
+/// `path` in a form that can be walked with `globwalk`.
+pub(crate) fn walk_root(path: &path::Path) -> std::io::Result<path::PathBuf> {
+    // `walkdir`, on Windows, seems to convert "." into "" which then fails.
+    path.canonicalize()
+}
+
+fn check_matches<S>(source: &path::Path, patterns: &[S]) -> Result<(), FixtureError>
+where
+    S: AsRef<str>,
+{
+    let mut unmatched = Vec::new();
+    for pattern in patterns {
+        let pattern = pattern.as_ref();
+        if pattern.starts_with('!') {
+            continue;
+        }
+        let mut walker = globwalk::GlobWalkerBuilder::from_patterns(source, &[pattern])
+            .follow_links(true)
+            .build()
+            .chain(FixtureError::new(FixtureKind::Walk).with_path(source))?;
+        if walker
+            .next()
+            .transpose()
+            .map_err(|e| walk_error(source, e))?
+            .is_none()
+        {
+            unmatched.push(pattern.to_owned());
+        }
+    }
+    if unmatched.is_empty() {
+        Ok(())
+    } else {
+        Err(UnmatchedPatterns(unmatched))
+            .chain(FixtureError::new(FixtureKind::UnmatchedPattern).with_path(source))
+    }
+}
+
+fn walk_error(source: &path::Path, err: globwalk::WalkError) -> FixtureError {
+    let path = err.path().unwrap_or(source).to_owned();
+    FixtureError::new(FixtureKind::Walk)
+        .with_path(path)
+        .chain(err)
+}
+
+#[derive(Debug)]
+struct UnmatchedPatterns(Vec<String>);
+
+impl std::fmt::Display for UnmatchedPatterns {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "no entries matched ")?;
+        for (i, pattern) in self.0.iter().enumerate() {
+            if i != 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "`{pattern}`")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for UnmatchedPatterns {}
+
+fn copy_parallel(
+    files: &[(path::PathBuf, path::PathBuf)],
+    options: &CopyOptions,
+    jobs: usize,
+) -> Result<CopyReport, FixtureError> {
+    let next = AtomicUsize::new(0);
+    let failed = AtomicBool::new(false);
+    // Files are claimed in order, so once a failure is seen, every earlier file has already been
+    // claimed and we can stop handing out work while still reporting the earliest failure.
+    let first_error: Mutex<Option<(usize, FixtureError)>> = Mutex::new(None);
+    let reports = std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..jobs)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut report = CopyReport::default();
+                    while !failed.load(Ordering::Relaxed) {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some((source_path, target_path)) = files.get(index) else {
+                            break;
+                        };
+                        match copy_entry(source_path, target_path, options) {
+                            Ok(used) => report.record(used),
+                            Err(err) => {
+                                failed.store(true, Ordering::Relaxed);
+                                let mut first_error =
+                                    first_error.lock().unwrap_or_else(|e| e.into_inner());
+                                if first_error.as_ref().is_none_or(|(i, _)| index < *i) {
+                                    *first_error = Some((index, err));
+                                }
+                            }
+                        }
+                    }
+                    report
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .map(|worker| worker.join().expect("copy workers don't panic"))
+            .collect::<Vec<_>>()
+    });
+
+    if let Some((_, err)) = first_error.into_inner().unwrap_or_else(|e| e.into_inner()) {
+        return Err(err);
+    }
+    let mut report = CopyReport::default();
+    for worker in reports {
+        report.copied += worker.copied;
+        report.reflinked += worker.reflinked;
+        report.hard_linked += worker.hard_linked;
+    }
+    Ok(report)
+}
+
+fn copy_entry(
+    source: &path::Path,
+    target: &path::Path,
+    options: &CopyOptions,
+) -> Result<CopyStrategy, FixtureError> {
+    let error = |kind| {
+        FixtureError::new(kind)
+            .with_path(target)
+            .with_source_path(source)
+    };
+    if options.strategy == CopyStrategy::HardLink {
+        clear_target(target)?;
+    }
+    let used = copy_file(source, target, options.strategy).chain(error(FixtureKind::CopyFile))?;
+    if options.preserve_metadata && used != CopyStrategy::HardLink {
+        let metadata = fs::metadata(source).chain(error(FixtureKind::Metadata))?;
+        set_mtime(target, &metadata).chain(error(FixtureKind::Metadata))?;
+    }
+    Ok(used)
+}
+
+/// Returns the strategy that was actually used
+fn copy_file(
+    source: &path::Path,
+    target: &path::Path,
+    strategy: CopyStrategy,
+) -> std::io::Result<CopyStrategy> {
+    match strategy {
+        CopyStrategy::Copy => {}
+        CopyStrategy::Reflink => {
+            // `reflink` refuses to overwrite, leaving that to the `fs::copy` fallback
+            if reflink_copy::reflink(source, target).is_ok() {
+                // Unlike `fs::copy`, cloning doesn't carry over the permissions
+                fs::set_permissions(target, fs::metadata(source)?.permissions())?;
+                return Ok(CopyStrategy::Reflink);
+            }
+        }
+        CopyStrategy::HardLink => {
+            if fs::hard_link(source, target).is_ok() {
+                return Ok(CopyStrategy::HardLink);
+            }
+        }
+    }
+    fs::copy(source, target)?;
+    Ok(CopyStrategy::Copy)
+}
+
+/// Remove the file at `target` so it can be replaced by a hard-link, which can't overwrite it.
+fn clear_target(target: &path::Path) -> Result<(), FixtureError> {
+    let Ok(metadata) = target.symlink_metadata() else {
+        return Ok(());
+    };
+    if metadata.is_dir() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::AlreadyExists,
+            "a directory is in the way",
+        ))
+        .chain(FixtureError::new(FixtureKind::CopyFile).with_path(target));
+    }
+    fs::remove_file(target).chain(FixtureError::new(FixtureKind::Remove).with_path(target))
+}
+
+fn set_mtime(path: &path::Path, metadata: &fs::Metadata) -> std::io::Result<()> {
+    let mtime = filetime::FileTime::from_last_modification_time(metadata);
+    filetime::set_file_mtime(path, mtime)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::fixture::PathChild;
+    use crate::fixture::PathCopyWith;
+    use crate::fixture::TempDir;
+
+    #[test]
+    fn preserve_metadata() {
+        let source = TempDir::new().unwrap();
+        let sub = source.child("sub");
+        fs::create_dir_all(sub.path()).unwrap();
+        fs::write(sub.child("hello.txt").path(), "hello").unwrap();
+        let old = filetime::FileTime::from_unix_time(1_000_000_000, 0);
+        filetime::set_file_mtime(sub.child("hello.txt").path(), old).unwrap();
+        filetime::set_file_mtime(sub.path(), old).unwrap();
+
+        let target = TempDir::new().unwrap();
+        let report = target
+            .copy_from_with(
+                source.path(),
+                &["**"],
+                &CopyOptions::new().preserve_metadata(true),
+            )
+            .unwrap();
+        assert_eq!(report.files(), 1);
+
+        for rel in ["sub", "sub/hello.txt"] {
+            let metadata = fs::metadata(target.child(rel).path()).unwrap();
+            assert_eq!(
+                filetime::FileTime::from_last_modification_time(&metadata),
+                old,
+                "{rel}"
+            );
+        }
+    }
+
+    #[test]
+    fn preserve_metadata_skips_root() {
+        let source = TempDir::new().unwrap();
+        fs::write(source.child("hello.txt").path(), "hello").unwrap();
+        let writable = fs::metadata(source.path()).unwrap().permissions();
+        let mut permissions = writable.clone();
+        permissions.set_readonly(true);
+        fs::set_permissions(source.path(), permissions).unwrap();
+
+        let target = TempDir::new().unwrap();
+        let result = target.copy_from_with(
+            source.path(),
+            &["**"],
+            &CopyOptions::new().preserve_metadata(true),
+        );
+
+        fs::set_permissions(source.path(), writable).unwrap();
+        assert_eq!(result.unwrap().files(), 1);
+        assert!(
+            !fs::metadata(target.path())
+                .unwrap()
+                .permissions()
+                .readonly()
+        );
+        target.close().unwrap();
+    }
+
+    #[test]
+    fn hard_link() {
+        // Keep both on the same filesystem so linking can succeed
+        let temp = TempDir::new().unwrap();
+        let source = temp.child("source");
+        fs::create_dir_all(source.path()).unwrap();
+        fs::write(source.child("hello.txt").path(), "hello").unwrap();
+
+        let target = temp.child("target");
+        let report = target
+            .copy_from_with(
+                source.path(),
+                &["*.txt"],
+                &CopyOptions::new().strategy(CopyStrategy::HardLink),
+            )
+            .unwrap();
+        assert_eq!(report.hard_linked(), 1);
+        assert_eq!(
+            fs::read_to_string(target.child("hello.txt").path()).unwrap(),
+            "hello"
+        );
+    }
+
+    #[test]
+    fn hard_link_over_existing() {
+        let temp = TempDir::new().unwrap();
+        let source = temp.child("source");
+        fs::create_dir_all(source.child("sub").path()).unwrap();
+        fs::write(source.child("sub/hello.txt").path(), "hello").unwrap();
+        let options = CopyOptions::new().strategy(CopyStrategy::HardLink);
+
+        let target = temp.child("target");
+        target
+            .copy_from_with(source.path(), &["**"], &options)
+            .unwrap();
+        let report = target
+            .copy_from_with(source.path(), &["**"], &options)
+            .unwrap();
+        assert_eq!(report.hard_linked(), 1);
+        assert_eq!(
+            fs::read_to_string(target.child("sub/hello.txt").path()).unwrap(),
+            "hello"
+        );
+
+        let blocked = temp.child("blocked");
+        fs::create_dir_all(blocked.child("sub/hello.txt").path()).unwrap();
+        let err = blocked
+            .copy_from_with(source.path(), &["**"], &options)
+            .unwrap_err();
+        assert_eq!(err.kind(), FixtureKind::CopyFile);
+        assert_eq!(err.path(), Some(blocked.child("sub/hello.txt").path()));
+        assert_eq!(err.io_error_kind(), Some(std::io::ErrorKind::AlreadyExists));
+    }
+
+    #[test]
+    fn parallel_matches_sequential() {
+        let source = TempDir::new().unwrap();
+        for dir in 0..5 {
+            for file in 0..20 {
+                let path = source.child(format!("dir{dir}/sub/file{file}.txt"));
+                fs::create_dir_all(path.parent().unwrap()).unwrap();
+                fs::write(path.path(), format!("{dir} {file}")).unwrap();
+            }
+        }
+
+        let sequential = TempDir::new().unwrap();
+        let sequential_report = sequential
+            .copy_from_with(source.path(), &["**"], &CopyOptions::new())
+            .unwrap();
+        let parallel = TempDir::new().unwrap();
+        let parallel_report = parallel
+            .copy_from_with(source.path(), &["**"], &CopyOptions::new().jobs(4))
+            .unwrap();
+        assert_eq!(sequential_report, parallel_report);
+        assert_eq!(parallel_report.files(), 100);
+
+        let list = |root: &path::Path| {
+            let mut entries = globwalk::GlobWalkerBuilder::from_patterns(root, &["**"])
+                .build()
+                .unwrap()
+                .map(|e| {
+                    let e = e.unwrap();
+                    let rel = e.path().strip_prefix(root).unwrap().to_owned();
+                    let content = e.file_type().is_file().then(|| fs::read(e.path()).unwrap());
+                    (rel, content)
+                })
+                .collect::<Vec<_>>();
+            entries.sort();
+            entries
+        };
+        assert_eq!(list(sequential.path()), list(parallel.path()));
+    }
+
+    #[test]
+    fn require_matches() {
+        let source = TempDir::new().unwrap();
+        fs::write(source.child("hello.txt").path(), "hello").unwrap();
+
+        let target = TempDir::new().unwrap();
+        let err = target
+            .copy_from_with(
+                source.path(),
+                &["*.txt", "*.tx", "!*.md", "sub/"],
+                &CopyOptions::new().require_matches(true),
+            )
+            .unwrap_err();
+        assert_eq!(err.kind(), FixtureKind::UnmatchedPattern);
+        assert!(
+            err.to_string()
+                .ends_with("no entries matched `*.tx`, `sub/`"),
+            "{err}"
+        );
+        assert!(!target.child("hello.txt").exists());
+    }
+}
@@ -0,0 +1,396 @@
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::io::Read;
+use std::io::Seek;
+use std::io::Write;
+use std::ops;
+use std::path;
+
+use super::ChildPath;
+use super::NamedTempFile;
+use super::errors::ChainError;
+use super::errors::FixtureError;
+use super::errors::FixtureKind;
+use super::errors::ResultChainExt;
+
+/// Damage a file at [`ChildPath`] to test how it gets handled.
+///
+/// Offsets and lengths must be within the file.
+///
+pub trait FileCorrupt {
+    /// Shorten the file to `len` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let file = temp.child("foo.txt");
+    /// file.write_str("To be or not to be...").unwrap();
+    /// file.truncate(5).unwrap();
+    /// file.assert("To be");
+    /// temp.close().unwrap();
+    /// ```
+    fn truncate(&self, len: u64) -> Result<(), FixtureError>;
+
+    /// Invert every bit of the bytes at `offsets`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let file = temp.child("foo.bin");
+    /// file.write_binary(&[0x00, 0x0f, 0xff]).unwrap();
+    /// file.flip_bytes(&[0, 2]).unwrap();
+    /// file.assert(b"\xff\x0f\x00");
+    /// temp.close().unwrap();
+    /// ```
+    fn flip_bytes(&self, offsets: &[u64]) -> Result<(), FixtureError>;
+
+    /// Replace the bytes starting at `offset` with `data`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let file = temp.child("foo.txt");
+    /// file.write_str("To be or not to be...").unwrap();
+    /// file.overwrite_bytes(3, b"do").unwrap();
+    /// file.assert("To do or not to be...");
+    /// temp.close().unwrap();
+    /// ```
+    fn overwrite_bytes(&self, offset: u64, data: &[u8]) -> Result<(), FixtureError>;
+
+    /// Set the bytes in `range` to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let file = temp.child("foo.bin");
+    /// file.write_binary(&[1, 2, 3, 4]).unwrap();
+    /// file.zero_range(1..3).unwrap();
+    /// file.assert(b"\x01\x00\x00\x04");
+    /// temp.close().unwrap();
+    /// ```
+    fn zero_range(&self, range: ops::Range<u64>) -> Result<(), FixtureError>;
+
+    /// Alter `count` bytes at pseudo-random offsets derived from `seed`.
+    ///
+    /// The same `seed` always corrupts a file of a given length the same way.  The returned
+    /// [`Corruption`] records the `seed` and what was changed, so include it in assertion messages
+    /// to be able to reproduce a failure.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let file = temp.child("foo.txt");
+    /// for seed in 0..10 {
+    ///     file.write_str("To be or not to be...").unwrap();
+    ///     let corruption = file.corrupt_random(seed, 2).unwrap();
+    ///     let content = std::fs::read(file.path()).unwrap();
+    ///     assert_ne!(content, b"To be or not to be...", "{corruption}");
+    /// }
+    /// temp.close().unwrap();
+    /// ```
+    fn corrupt_random(&self, seed: u64, count: usize) -> Result<Corruption, FixtureError>;
+}
+
+impl FileCorrupt for ChildPath {
+    fn truncate(&self, len: u64) -> Result<(), FixtureError> {
+        truncate(self.path(), len)
+    }
+
+    fn flip_bytes(&self, offsets: &[u64]) -> Result<(), FixtureError> {
+        flip_bytes(self.path(), offsets)
+    }
+
+    fn overwrite_bytes(&self, offset: u64, data: &[u8]) -> Result<(), FixtureError> {
+        overwrite_bytes(self.path(), offset, data)
+    }
+
+    fn zero_range(&self, range: ops::Range<u64>) -> Result<(), FixtureError> {
+        zero_range(self.path(), range)
+    }
+
+    fn corrupt_random(&self, seed: u64, count: usize) -> Result<Corruption, FixtureError> {
+        corrupt_random(self.path(), seed, count)
+    }
+}
+
+impl FileCorrupt for NamedTempFile {
+    fn truncate(&self, len: u64) -> Result<(), FixtureError> {
+        truncate(self.path(), len)
+    }
+
+    fn flip_bytes(&self, offsets: &[u64]) -> Result<(), FixtureError> {
+        flip_bytes(self.path(), offsets)
+    }
+
+    fn overwrite_bytes(&self, offset: u64, data: &[u8]) -> Result<(), FixtureError> {
+        overwrite_bytes(self.path(), offset, data)
+    }
+
+    fn zero_range(&self, range: ops::Range<u64>) -> Result<(), FixtureError> {
+        zero_range(self.path(), range)
+    }
+
+    fn corrupt_random(&self, seed: u64, count: usize) -> Result<Corruption, FixtureError> {
+        corrupt_random(self.path(), seed, count)
+    }
+}
+
+/// Record of [`FileCorrupt::corrupt_random`], for reproducing failures.
+///
+/// The [`Display`][fmt::Display] output includes the seed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Corruption {
+    seed: u64,
+    changes: Vec<(u64, u8, u8)>,
+}
+
+impl Corruption {
+    /// The seed the corruption was derived from.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// The offsets of the altered bytes, in increasing order.
+    pub fn offsets(&self) -> impl Iterator<Item = u64> + '_ {
+        self.changes.iter().map(|(offset, _, _)| *offset)
+    }
+}
+
+impl fmt::Display for Corruption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "corrupted with seed {}:", self.seed)?;
+        for (offset, old, new) in &self.changes {
+            write!(f, " {offset}={old:#04x}->{new:#04x}")?;
+        }
+        Ok(())
+    }
+}
+
+fn open(path: &path::Path) -> Result<(fs::File, u64), FixtureError> {
+    let file = fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(path)
+        .chain(FixtureError::new(FixtureKind::WriteFile).with_path(path))?;
+    let len = file
+        .metadata()
+        .chain(FixtureError::new(FixtureKind::Metadata).with_path(path))?
+        .len();
+    Ok((file, len))
+}
+
+fn check_range(path: &path::Path, range: ops::Range<u64>, len: u64) -> Result<(), FixtureError> {
+    if range.start <= range.end && range.end <= len {
+        Ok(())
+    } else {
+        Err(FixtureError::new(FixtureKind::WriteFile)
+            .with_path(path)
+            .chain(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "{}..{} is out of range for a file with {len} bytes",
+                    range.start, range.end
+                ),
+            )))
+    }
+}
+
+fn read_at(file: &mut fs::File, offset: u64, buf: &mut [u8]) -> io::Result<()> {
+    file.seek(io::SeekFrom::Start(offset))?;
+    file.read_exact(buf)
+}
+
+fn write_at(file: &mut fs::File, offset: u64, data: &[u8]) -> io::Result<()> {
+    file.seek(io::SeekFrom::Start(offset))?;
+    file.write_all(data)
+}
+
+fn truncate(path: &path::Path, len: u64) -> Result<(), FixtureError> {
+    let (file, current) = open(path)?;
+    check_range(path, 0..len, current)?;
+    file.set_len(len)
+        .chain(FixtureError::new(FixtureKind::WriteFile).with_path(path))?;
+    Ok(())
+}
+
+fn flip_bytes(path: &path::Path, offsets: &[u64]) -> Result<(), FixtureError> {
+    let (mut file, len) = open(path)?;
+    for &offset in offsets {
+        check_range(path, offset..offset.saturating_add(1), len)?;
+    }
+    for &offset in offsets {
+        let mut byte = [0];
+        read_at(&mut file, offset, &mut byte)
+            .chain(FixtureError::new(FixtureKind::ReadFile).with_path(path))?;
+        write_at(&mut file, offset, &[!byte[0]])
+            .chain(FixtureError::new(FixtureKind::WriteFile).with_path(path))?;
+    }
+    Ok(())
+}
+
+fn overwrite_bytes(path: &path::Path, offset: u64, data: &[u8]) -> Result<(), FixtureError> {
+    let (mut file, len) = open(path)?;
+    let end = offset.saturating_add(data.len() as u64);
+    check_range(path, offset..end, len)?;
+    write_at(&mut file, offset, data)
+        .chain(FixtureError::new(FixtureKind::WriteFile).with_path(path))?;
+    Ok(())
+}
+
+fn zero_range(path: &path::Path, range: ops::Range<u64>) -> Result<(), FixtureError> {
+    let (mut file, len) = open(path)?;
+    check_range(path, range.clone(), len)?;
+    let zeros = [0; 4096];
+    let mut offset = range.start;
+    while offset < range.end {
+        let chunk = usize::try_from(range.end - offset)
+            .map(|n| n.min(zeros.len()))
+            .unwrap_or(zeros.len());
+        write_at(&mut file, offset, &zeros[..chunk])
+            .chain(FixtureError::new(FixtureKind::WriteFile).with_path(path))?;
+        offset += chunk as u64;
+    }
+    Ok(())
+}
+
+fn corrupt_random(path: &path::Path, seed: u64, count: usize) -> Result<Corruption, FixtureError> {
+    let (mut file, len) = open(path)?;
+    let count = u64::try_from(count).unwrap_or(u64::MAX);
+    if len < count {
+        return Err(FixtureError::new(FixtureKind::WriteFile)
+            .with_path(path)
+            .chain(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("can't corrupt {count} bytes of a file with {len} bytes (seed {seed})"),
+            )));
+    }
+
+    let mut rng = SplitMix64(seed);
+    let offsets = random_offsets(&mut rng, len, count);
+
+    let mut changes = Vec::with_capacity(offsets.len());
+    for offset in offsets {
+        let mut byte = [0];
+        read_at(&mut file, offset, &mut byte)
+            .chain(FixtureError::new(FixtureKind::ReadFile).with_path(path))?;
+        // Never a no-op
+        let mask = u8::try_from(rng.next() % 255).expect("less than 255") + 1;
+        let new = byte[0] ^ mask;
+        write_at(&mut file, offset, &[new])
+            .chain(FixtureError::new(FixtureKind::WriteFile).with_path(path))?;
+        changes.push((offset, byte[0], new));
+    }
+    Ok(Corruption { seed, changes })
+}
+
+/// Pick `count` distinct offsets below `len`, in ascending order.
+fn random_offsets(rng: &mut SplitMix64, len: u64, count: u64) -> Vec<u64> {
+    let mut offsets = if len / 2 < count {
+        // Retrying collisions gets slow once most offsets are picked, so shuffle the first
+        // `count` of every offset instead, which `len` being at most twice `count` keeps cheap.
+        let mut offsets: Vec<u64> = (0..len).collect();
+        for (i, start) in (0..count).enumerate() {
+            let j = start + rng.next() % (len - start);
+            offsets.swap(i, usize::try_from(j).expect("within `offsets`"));
+        }
+        offsets.truncate(usize::try_from(count).expect("within `offsets`"));
+        offsets
+    } else {
+        let mut offsets = BTreeSet::new();
+        while (offsets.len() as u64) < count {
+            offsets.insert(rng.next() % len);
+        }
+        offsets.into_iter().collect()
+    };
+    offsets.sort_unstable();
+    offsets
+}
+
+/// Small, fast, and stable across platforms and releases, unlike general purpose RNGs.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::fixture::FileWriteBin;
+    use crate::fixture::PathChild;
+    use crate::fixture::TempDir;
+
+    #[test]
+    fn corrupt_random_is_reproducible() {
+        let temp = TempDir::new().unwrap();
+        let file = temp.child("data.bin");
+        let original = [0x55; 64];
+
+        file.write_binary(&original).unwrap();
+        let first = file.corrupt_random(7, 5).unwrap();
+        let first_content = fs::read(file.path()).unwrap();
+
+        file.write_binary(&original).unwrap();
+        let second = file.corrupt_random(7, 5).unwrap();
+        let second_content = fs::read(file.path()).unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(first_content, second_content);
+        assert_eq!(first.offsets().count(), 5);
+        let changed = original
+            .iter()
+            .zip(&first_content)
+            .filter(|(a, b)| a != b)
+            .count();
+        assert_eq!(changed, 5);
+        assert!(first.to_string().contains("seed 7"), "{first}");
+    }
+
+    #[test]
+    fn corrupt_random_every_byte() {
+        let temp = TempDir::new().unwrap();
+        let file = temp.child("data.bin");
+        let original = [0x55; 64];
+        file.write_binary(&original).unwrap();
+
+        let corruption = file.corrupt_random(7, original.len()).unwrap();
+        assert!(corruption.offsets().eq(0..64));
+        let content = fs::read(file.path()).unwrap();
+        assert!(content.iter().all(|byte| *byte != 0x55));
+    }
+
+    #[test]
+    fn out_of_range() {
+        let temp = TempDir::new().unwrap();
+        let file = temp.child("data.bin");
+        file.write_binary(&[1, 2, 3]).unwrap();
+
+        let err = file.overwrite_bytes(2, &[0, 0]).unwrap_err();
+        assert_eq!(err.io_error_kind(), Some(io::ErrorKind::InvalidInput));
+        assert_eq!(fs::read(file.path()).unwrap(), [1, 2, 3]);
+    }
+}
@@ -85,7 +85,8 @@ impl TempDir {
     /// tmp_dir.close().unwrap();
     /// ```
     pub fn new() -> Result<Self, FixtureError> {
-        let temp = tempfile::TempDir::new().chain(FixtureError::new(FixtureKind::CreateDir))?;
+        let temp = tempfile::TempDir::new()
+            .chain(FixtureError::new(FixtureKind::CreateDir).with_path(std::env::temp_dir()))?;
         let temp = Inner::Temp(temp);
         Ok(Self { temp })
     }
@@ -111,8 +112,9 @@ impl TempDir {
     /// tmp_dir.close().unwrap();
     /// ```
     pub fn new_in<P: AsRef<path::Path>>(dir: P) -> Result<Self, FixtureError> {
-        let temp =
-            tempfile::TempDir::new_in(dir).chain(FixtureError::new(FixtureKind::CreateDir))?;
+        let dir = dir.as_ref();
+        let temp = tempfile::TempDir::new_in(dir)
+            .chain(FixtureError::new(FixtureKind::CreateDir).with_path(dir))?;
         let temp = Inner::Temp(temp);
         Ok(Self { temp })
     }
@@ -139,7 +141,7 @@ impl TempDir {
     /// ```
     pub fn with_prefix<S: AsRef<OsStr>>(prefix: S) -> Result<Self, FixtureError> {
         let temp = tempfile::TempDir::with_prefix(prefix)
-            .chain(FixtureError::new(FixtureKind::CreateDir))?;
+            .chain(FixtureError::new(FixtureKind::CreateDir).with_path(std::env::temp_dir()))?;
         let temp = Inner::Temp(temp);
         Ok(Self { temp })
     }
@@ -169,8 +171,9 @@ impl TempDir {
         prefix: S,
         dir: P,
     ) -> Result<Self, FixtureError> {
+        let dir = dir.as_ref();
         let temp = tempfile::TempDir::with_prefix_in(prefix, dir)
-            .chain(FixtureError::new(FixtureKind::CreateDir))?;
+            .chain(FixtureError::new(FixtureKind::CreateDir).with_path(dir))?;
         let temp = Inner::Temp(temp);
         Ok(Self { temp })
     }
@@ -217,7 +220,7 @@ impl TempDir {
     /// ```
     pub fn into_persistent(self) -> Self {
         let path = match self.temp {
-            Inner::Temp(temp) => temp.into_path(),
+            Inner::Temp(temp) => temp.keep(),
             Inner::Persisted(path) => path,
         };
         let temp = Inner::Persisted(path);
@@ -272,9 +275,11 @@ impl TempDir {
     /// ```
     pub fn close(self) -> Result<(), FixtureError> {
         match self.temp {
-            Inner::Temp(temp) => temp
-                .close()
-                .chain(FixtureError::new(FixtureKind::Cleanup))?,
+            Inner::Temp(temp) => {
+                let path = temp.path().to_owned();
+                temp.close()
+                    .chain(FixtureError::new(FixtureKind::Cleanup).with_path(path))?;
+            }
             Inner::Persisted(_) => (),
         }
         Ok(())
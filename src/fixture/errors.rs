@@ -2,6 +2,8 @@
 
 use std::error::Error;
 use std::fmt;
+use std::io;
+use std::path;
 
 pub(crate) trait ChainError {
     fn chain<F>(self, cause: F) -> Self
@@ -43,6 +45,24 @@ pub enum FixtureKind {
     Cleanup,
     /// Failed to create symlink
     Symlink,
+    /// A pattern matched no entries.
+    UnmatchedPattern,
+    /// Failed when reading a file.
+    ReadFile,
+    /// Failed when reading a directory.
+    ReadDir,
+    /// Failed when removing a file or directory.
+    Remove,
+    /// Failed when renaming a file or directory.
+    Rename,
+    /// Failed when setting permissions.
+    Permissions,
+    /// Failed when parsing the content of a file.
+    Parse,
+    /// Failed when reading or updating metadata, like timestamps.
+    Metadata,
+    /// Failed when waiting for another process to build a fixture.
+    Lock,
 }
 
 impl fmt::Display for FixtureKind {
@@ -54,6 +74,15 @@ impl fmt::Display for FixtureKind {
             Self::CreateDir => write!(f, "Failed when creating a directory."),
             Self::Cleanup => write!(f, "Failed to cleanup fixture."),
             Self::Symlink => write!(f, "Failed when symlinking to the target."),
+            Self::UnmatchedPattern => write!(f, "Failed when a pattern matched nothing."),
+            Self::ReadFile => write!(f, "Failed when reading a file."),
+            Self::ReadDir => write!(f, "Failed when reading a directory."),
+            Self::Remove => write!(f, "Failed when removing a path."),
+            Self::Rename => write!(f, "Failed when renaming a path."),
+            Self::Permissions => write!(f, "Failed when setting permissions."),
+            Self::Parse => write!(f, "Failed when parsing a file."),
+            Self::Metadata => write!(f, "Failed when accessing metadata."),
+            Self::Lock => write!(f, "Failed when waiting for a lock."),
         }
     }
 }
@@ -62,19 +91,91 @@ impl fmt::Display for FixtureKind {
 #[derive(Debug)]
 pub struct FixtureError {
     kind: FixtureKind,
+    path: Option<path::PathBuf>,
+    source_path: Option<path::PathBuf>,
     cause: Option<Box<dyn Error + Send + Sync + 'static>>,
 }
 
 impl FixtureError {
     /// Create a `FixtureError`.
     pub fn new(kind: FixtureKind) -> Self {
-        Self { kind, cause: None }
+        Self {
+            kind,
+            path: None,
+            source_path: None,
+            cause: None,
+        }
+    }
+
+    /// Record the path being operated on.
+    ///
+    /// For copies, this is the destination.
+    pub fn with_path(mut self, path: impl Into<path::PathBuf>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Record the path being copied from.
+    pub fn with_source_path(mut self, path: impl Into<path::PathBuf>) -> Self {
+        self.source_path = Some(path.into());
+        self
     }
 
     /// Fixture initialization cause.
     pub fn kind(&self) -> FixtureKind {
         self.kind
     }
+
+    /// The path being operated on, if known.
+    ///
+    /// For copies, this is the destination.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let file = temp.child("foo.txt");
+    /// let err = file.write_file(&temp.child("missing.txt")).unwrap_err();
+    /// assert_eq!(err.path(), Some(file.path()));
+    /// assert_eq!(err.source_path(), Some(temp.child("missing.txt").path()));
+    /// temp.close().unwrap();
+    /// ```
+    pub fn path(&self) -> Option<&path::Path> {
+        self.path.as_deref()
+    }
+
+    /// The path being copied from, if any.
+    pub fn source_path(&self) -> Option<&path::Path> {
+        self.source_path.as_deref()
+    }
+
+    /// The kind of the underlying [`io::Error`], if any.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let err = temp
+    ///     .child("foo.txt")
+    ///     .write_file(&temp.child("missing.txt"))
+    ///     .unwrap_err();
+    /// assert_eq!(err.io_error_kind(), Some(std::io::ErrorKind::NotFound));
+    /// temp.close().unwrap();
+    /// ```
+    pub fn io_error_kind(&self) -> Option<io::ErrorKind> {
+        let mut cause = self.source();
+        while let Some(current) = cause {
+            if let Some(err) = current.downcast_ref::<io::Error>() {
+                return Some(err.kind());
+            }
+            cause = current.source();
+        }
+        None
+    }
 }
 
 impl Error for FixtureError {
@@ -82,9 +183,9 @@ impl Error for FixtureError {
         "Failed to initialize fixture"
     }
 
-    fn cause(&self) -> Option<&dyn Error> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
         self.cause.as_ref().map(|c| {
-            let c: &dyn Error = c.as_ref();
+            let c: &(dyn Error + 'static) = c.as_ref();
             c
         })
     }
@@ -92,14 +193,24 @@ impl Error for FixtureError {
 
 impl fmt::Display for FixtureError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self.cause {
-            Some(ref cause) => write!(
-                f,
-                "Failed to initialize fixture: {}\nCause: {}",
-                self.kind, cause
-            ),
-            None => write!(f, "Failed to initialize fixture: {}", self.kind),
+        write!(f, "Failed to initialize fixture: {}", self.kind)?;
+        if let Some(ref source_path) = self.source_path {
+            write!(f, "\nSource: {}", source_path.display())?;
+        }
+        if let Some(ref path) = self.path {
+            write!(f, "\nPath: {}", path.display())?;
         }
+        if let Some(ref cause) = self.cause {
+            write!(f, "\nCause: {cause}")?;
+        }
+        Ok(())
+    }
+}
+
+impl From<FixtureError> for io::Error {
+    fn from(err: FixtureError) -> Self {
+        let kind = err.io_error_kind().unwrap_or(io::ErrorKind::Other);
+        Self::new(kind, err)
     }
 }
 
@@ -88,7 +88,8 @@ impl NamedTempFile {
     where
         S: AsRef<ffi::OsStr>,
     {
-        let temp = tempfile::TempDir::new().chain(FixtureError::new(FixtureKind::CreateDir))?;
+        let temp = tempfile::TempDir::new()
+            .chain(FixtureError::new(FixtureKind::CreateDir).with_path(std::env::temp_dir()))?;
         let path = temp.path().join(name.as_ref());
         let temp = Inner::Temp(temp);
         Ok(Self { temp, path })
@@ -138,7 +139,7 @@ impl NamedTempFile {
         let mut temp = Inner::Persisted;
         ::std::mem::swap(&mut self.temp, &mut temp);
         if let Inner::Temp(temp) = temp {
-            _ = temp.into_path();
+            _ = temp.keep();
         }
 
         self
@@ -190,7 +191,7 @@ impl NamedTempFile {
         match self.temp {
             Inner::Temp(temp) => temp
                 .close()
-                .chain(FixtureError::new(FixtureKind::Cleanup))?,
+                .chain(FixtureError::new(FixtureKind::Cleanup).with_path(self.path))?,
             Inner::Persisted => (),
         }
         Ok(())
@@ -1,13 +1,23 @@
 //! Initialize the filesystem to use as test fixtures.
 
+mod cache;
 mod child;
+mod copy;
+mod corrupt;
 mod dir;
 mod errors;
 mod file;
+#[cfg(feature = "serde")]
+mod serialize;
 mod tools;
 
+pub use self::cache::*;
 pub use self::child::*;
+pub use self::copy::*;
+pub use self::corrupt::*;
 pub use self::dir::*;
 pub use self::errors::*;
 pub use self::file::*;
+#[cfg(feature = "serde")]
+pub use self::serialize::*;
 pub use self::tools::*;
@@ -0,0 +1,225 @@
+use std::path;
+
+use super::ChildPath;
+use super::NamedTempFile;
+use super::errors::FixtureError;
+use super::errors::FixtureKind;
+use super::errors::ResultChainExt;
+use super::tools::FileRead;
+use super::tools::FileWriteStr;
+
+/// Read and write JSON files at [`ChildPath`].
+///
+pub trait FileJson {
+    /// Deserialize the JSON file at [`ChildPath`].
+    ///
+    /// Parse errors report the line and column.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let file = temp.child("foo.json");
+    /// file.write_str(r#"{"name": "foo"}"#).unwrap();
+    /// let value: BTreeMap<String, String> = file.read_json().unwrap();
+    /// assert_eq!(value["name"], "foo");
+    /// temp.close().unwrap();
+    /// ```
+    fn read_json<T>(&self) -> Result<T, FixtureError>
+    where
+        T: serde::de::DeserializeOwned;
+
+    /// Serialize `value` as pretty-printed JSON to the file at [`ChildPath`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let file = temp.child("foo.json");
+    /// file.write_json(&BTreeMap::from([("name", "foo")])).unwrap();
+    /// file.assert("{\n  \"name\": \"foo\"\n}\n");
+    /// temp.close().unwrap();
+    /// ```
+    fn write_json<T>(&self, value: &T) -> Result<(), FixtureError>
+    where
+        T: serde::Serialize + ?Sized;
+}
+
+impl FileJson for ChildPath {
+    fn read_json<T>(&self) -> Result<T, FixtureError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let content = self.read_to_string()?;
+        parse_json(self.path(), &content)
+    }
+
+    fn write_json<T>(&self, value: &T) -> Result<(), FixtureError>
+    where
+        T: serde::Serialize + ?Sized,
+    {
+        self.write_str(&to_json(self.path(), value)?)
+    }
+}
+
+impl FileJson for NamedTempFile {
+    fn read_json<T>(&self) -> Result<T, FixtureError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let content = self.read_to_string()?;
+        parse_json(self.path(), &content)
+    }
+
+    fn write_json<T>(&self, value: &T) -> Result<(), FixtureError>
+    where
+        T: serde::Serialize + ?Sized,
+    {
+        self.write_str(&to_json(self.path(), value)?)
+    }
+}
+
+/// Read and write TOML files at [`ChildPath`].
+///
+pub trait FileToml {
+    /// Deserialize the TOML file at [`ChildPath`].
+    ///
+    /// Parse errors report the line and column.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let file = temp.child("foo.toml");
+    /// file.write_str("name = \"foo\"\n").unwrap();
+    /// let value: BTreeMap<String, String> = file.read_toml().unwrap();
+    /// assert_eq!(value["name"], "foo");
+    /// temp.close().unwrap();
+    /// ```
+    fn read_toml<T>(&self) -> Result<T, FixtureError>
+    where
+        T: serde::de::DeserializeOwned;
+
+    /// Serialize `value` as pretty-printed TOML to the file at [`ChildPath`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let file = temp.child("foo.toml");
+    /// file.write_toml(&BTreeMap::from([("name", "foo")])).unwrap();
+    /// file.assert("name = \"foo\"\n");
+    /// temp.close().unwrap();
+    /// ```
+    fn write_toml<T>(&self, value: &T) -> Result<(), FixtureError>
+    where
+        T: serde::Serialize + ?Sized;
+}
+
+impl FileToml for ChildPath {
+    fn read_toml<T>(&self) -> Result<T, FixtureError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let content = self.read_to_string()?;
+        parse_toml(self.path(), &content)
+    }
+
+    fn write_toml<T>(&self, value: &T) -> Result<(), FixtureError>
+    where
+        T: serde::Serialize + ?Sized,
+    {
+        self.write_str(&to_toml(self.path(), value)?)
+    }
+}
+
+impl FileToml for NamedTempFile {
+    fn read_toml<T>(&self) -> Result<T, FixtureError>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let content = self.read_to_string()?;
+        parse_toml(self.path(), &content)
+    }
+
+    fn write_toml<T>(&self, value: &T) -> Result<(), FixtureError>
+    where
+        T: serde::Serialize + ?Sized,
+    {
+        self.write_str(&to_toml(self.path(), value)?)
+    }
+}
+
+fn parse_json<T>(path: &path::Path, content: &str) -> Result<T, FixtureError>
+where
+    T: serde::de::DeserializeOwned,
+{
+    // `serde_json`'s errors include the line and column
+    serde_json::from_str(content).chain(FixtureError::new(FixtureKind::Parse).with_path(path))
+}
+
+fn to_json<T>(path: &path::Path, value: &T) -> Result<String, FixtureError>
+where
+    T: serde::Serialize + ?Sized,
+{
+    let mut content = serde_json::to_string_pretty(value)
+        .chain(FixtureError::new(FixtureKind::WriteFile).with_path(path))?;
+    content.push('\n');
+    Ok(content)
+}
+
+fn parse_toml<T>(path: &path::Path, content: &str) -> Result<T, FixtureError>
+where
+    T: serde::de::DeserializeOwned,
+{
+    // `toml`'s errors include the line and column, with a snippet of the document
+    toml::from_str(content).chain(FixtureError::new(FixtureKind::Parse).with_path(path))
+}
+
+fn to_toml<T>(path: &path::Path, value: &T) -> Result<String, FixtureError>
+where
+    T: serde::Serialize + ?Sized,
+{
+    toml::to_string_pretty(value).chain(FixtureError::new(FixtureKind::WriteFile).with_path(path))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::collections::BTreeMap;
+
+    use crate::TempDir;
+    use crate::fixture::PathChild;
+
+    #[test]
+    fn parse_error_location() {
+        let temp = TempDir::new().unwrap();
+
+        let json = temp.child("foo.json");
+        json.write_str("{\n  \"name\": foo\n}").unwrap();
+        let err = json.read_json::<BTreeMap<String, String>>().unwrap_err();
+        assert_eq!(err.kind(), FixtureKind::Parse);
+        assert_eq!(err.path(), Some(json.path()));
+        assert!(err.to_string().contains("line 2 column"), "{err}");
+
+        let toml = temp.child("foo.toml");
+        toml.write_str("[package]\nname = foo\n").unwrap();
+        let err = toml.read_toml::<BTreeMap<String, String>>().unwrap_err();
+        assert_eq!(err.kind(), FixtureKind::Parse);
+        assert_eq!(err.path(), Some(toml.path()));
+        assert!(err.to_string().contains("line 2, column"), "{err}");
+    }
+}
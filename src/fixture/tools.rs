@@ -5,8 +5,12 @@ use std::io::Write;
 use std::path;
 
 use super::ChildPath;
+use super::CopyOptions;
+use super::CopyReport;
 use super::NamedTempFile;
 use super::TempDir;
+use super::copy::copy_files;
+use super::errors::ChainError;
 use super::errors::FixtureError;
 use super::errors::FixtureKind;
 use super::errors::ResultChainExt;
@@ -131,6 +135,276 @@ impl FileWriteStr for NamedTempFile {
     }
 }
 
+/// Append binary data to a file at [`ChildPath`].
+///
+pub trait FileAppendBin {
+    /// Append binary data to a file at [`ChildPath`], creating it if needed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let file = temp.child("foo.txt");
+    /// file.write_binary(b"To be").unwrap();
+    /// file.append_binary(b" or not to be...").unwrap();
+    /// file.assert("To be or not to be...");
+    /// temp.close().unwrap();
+    /// ```
+    ///
+    fn append_binary(&self, data: &[u8]) -> Result<(), FixtureError>;
+}
+
+impl FileAppendBin for ChildPath {
+    fn append_binary(&self, data: &[u8]) -> Result<(), FixtureError> {
+        append_binary(self.path(), data)
+    }
+}
+
+impl FileAppendBin for NamedTempFile {
+    fn append_binary(&self, data: &[u8]) -> Result<(), FixtureError> {
+        append_binary(self.path(), data)
+    }
+}
+
+/// Append text to a file at [`ChildPath`].
+///
+pub trait FileAppendStr {
+    /// Append text to a file at [`ChildPath`], creating it if needed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let file = temp.child("foo.txt");
+    /// file.write_str("To be").unwrap();
+    /// file.append_str(" or not to be...").unwrap();
+    /// file.assert("To be or not to be...");
+    /// temp.close().unwrap();
+    /// ```
+    ///
+    fn append_str(&self, data: &str) -> Result<(), FixtureError>;
+}
+
+impl FileAppendStr for ChildPath {
+    fn append_str(&self, data: &str) -> Result<(), FixtureError> {
+        append_binary(self.path(), data.as_bytes())
+    }
+}
+
+impl FileAppendStr for NamedTempFile {
+    fn append_str(&self, data: &str) -> Result<(), FixtureError> {
+        append_binary(self.path(), data.as_bytes())
+    }
+}
+
+/// Edit a text file at [`ChildPath`] in-place.
+///
+/// Lines are indexed from `0` and keep the file's line endings.
+///
+pub trait FileEdit {
+    /// Replace all occurrences of `pattern` with `replacement`.
+    ///
+    /// Fails with [`FixtureKind::WriteFile`] if `pattern` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let file = temp.child("foo.txt");
+    /// file.write_str("To be or not to be...").unwrap();
+    /// file.replace_in_file("be", "do").unwrap();
+    /// file.assert("To do or not to do...");
+    /// temp.close().unwrap();
+    /// ```
+    ///
+    fn replace_in_file(&self, pattern: &str, replacement: &str) -> Result<(), FixtureError>;
+
+    /// Insert `line` so it becomes line number `index`.
+    ///
+    /// Fails if `index` is past the end of the file.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let file = temp.child("foo.txt");
+    /// file.write_str("one\nthree\n").unwrap();
+    /// file.insert_line(1, "two").unwrap();
+    /// file.assert("one\ntwo\nthree\n");
+    /// temp.close().unwrap();
+    /// ```
+    ///
+    fn insert_line(&self, index: usize, line: &str) -> Result<(), FixtureError>;
+
+    /// Delete line number `index`.
+    ///
+    /// Fails if there is no such line.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let file = temp.child("foo.txt");
+    /// file.write_str("one\ntwo\nthree\n").unwrap();
+    /// file.delete_line(1).unwrap();
+    /// file.assert("one\nthree\n");
+    /// temp.close().unwrap();
+    /// ```
+    ///
+    fn delete_line(&self, index: usize) -> Result<(), FixtureError>;
+}
+
+impl FileEdit for ChildPath {
+    fn replace_in_file(&self, pattern: &str, replacement: &str) -> Result<(), FixtureError> {
+        replace_in_file(self.path(), pattern, replacement)
+    }
+
+    fn insert_line(&self, index: usize, line: &str) -> Result<(), FixtureError> {
+        insert_line(self.path(), index, line)
+    }
+
+    fn delete_line(&self, index: usize) -> Result<(), FixtureError> {
+        delete_line(self.path(), index)
+    }
+}
+
+impl FileEdit for NamedTempFile {
+    fn replace_in_file(&self, pattern: &str, replacement: &str) -> Result<(), FixtureError> {
+        replace_in_file(self.path(), pattern, replacement)
+    }
+
+    fn insert_line(&self, index: usize, line: &str) -> Result<(), FixtureError> {
+        insert_line(self.path(), index, line)
+    }
+
+    fn delete_line(&self, index: usize) -> Result<(), FixtureError> {
+        delete_line(self.path(), index)
+    }
+}
+
+/// Read a file at [`ChildPath`].
+///
+pub trait FileRead {
+    /// Read the file at [`ChildPath`] as text.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let file = temp.child("foo.txt");
+    /// file.write_str("To be or not to be...").unwrap();
+    /// assert_eq!(file.read_to_string().unwrap(), "To be or not to be...");
+    /// temp.close().unwrap();
+    /// ```
+    fn read_to_string(&self) -> Result<String, FixtureError>;
+
+    /// Read the file at [`ChildPath`] as bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let file = temp.child("foo.bin");
+    /// file.write_binary(&[1, 2, 3]).unwrap();
+    /// assert_eq!(file.read_bytes().unwrap(), [1, 2, 3]);
+    /// temp.close().unwrap();
+    /// ```
+    fn read_bytes(&self) -> Result<Vec<u8>, FixtureError>;
+
+    /// Read the lines of the text file at [`ChildPath`], without their line endings.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let file = temp.child("foo.txt");
+    /// file.write_str("To be\r\nor not to be...\n").unwrap();
+    /// assert_eq!(file.read_lines().unwrap(), ["To be", "or not to be..."]);
+    /// temp.close().unwrap();
+    /// ```
+    fn read_lines(&self) -> Result<Vec<String>, FixtureError>;
+}
+
+impl FileRead for ChildPath {
+    fn read_to_string(&self) -> Result<String, FixtureError> {
+        read_to_string(self.path())
+    }
+
+    fn read_bytes(&self) -> Result<Vec<u8>, FixtureError> {
+        read_bytes(self.path())
+    }
+
+    fn read_lines(&self) -> Result<Vec<String>, FixtureError> {
+        read_lines(self.path())
+    }
+}
+
+impl FileRead for NamedTempFile {
+    fn read_to_string(&self) -> Result<String, FixtureError> {
+        read_to_string(self.path())
+    }
+
+    fn read_bytes(&self) -> Result<Vec<u8>, FixtureError> {
+        read_bytes(self.path())
+    }
+
+    fn read_lines(&self) -> Result<Vec<String>, FixtureError> {
+        read_lines(self.path())
+    }
+}
+
+/// List the directory at [`ChildPath`].
+///
+pub trait PathReadDir {
+    /// List the entries of the directory at [`ChildPath`], sorted by name.
+    ///
+    /// Only the immediate entries are listed, as full paths.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// temp.child("b.txt").touch().unwrap();
+    /// temp.child("a/c.txt").touch().unwrap();
+    /// assert_eq!(
+    ///     temp.read_dir_sorted().unwrap(),
+    ///     [temp.child("a").path(), temp.child("b.txt").path()]
+    /// );
+    /// temp.close().unwrap();
+    /// ```
+    fn read_dir_sorted(&self) -> Result<Vec<path::PathBuf>, FixtureError>;
+}
+
+impl PathReadDir for ChildPath {
+    fn read_dir_sorted(&self) -> Result<Vec<path::PathBuf>, FixtureError> {
+        read_dir_sorted(self.path())
+    }
+}
+
+impl PathReadDir for TempDir {
+    fn read_dir_sorted(&self) -> Result<Vec<path::PathBuf>, FixtureError> {
+        read_dir_sorted(self.path())
+    }
+}
+
 /// Write (copy) a file to [`ChildPath`].
 ///
 pub trait FileWriteFile {
@@ -186,13 +460,59 @@ pub trait PathCopy {
         S: AsRef<str>;
 }
 
+/// Copy files into [`TempDir`], customizing how with [`CopyOptions`].
+///
+pub trait PathCopyWith {
+    /// Copy files and directories into the current path from the `source` according to the glob
+    /// `patterns`, as controlled by `options`.
+    ///
+    /// Returns a [`CopyReport`] describing how the files were put in place.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    /// use assert_fs::fixture::CopyOptions;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// temp.copy_from_with(".", &["*.rs"], &CopyOptions::new().preserve_metadata(true))
+    ///     .unwrap();
+    /// temp.close().unwrap();
+    /// ```
+    fn copy_from_with<P, S>(
+        &self,
+        source: P,
+        patterns: &[S],
+        options: &CopyOptions,
+    ) -> Result<CopyReport, FixtureError>
+    where
+        P: AsRef<path::Path>,
+        S: AsRef<str>;
+}
+
 impl PathCopy for TempDir {
     fn copy_from<P, S>(&self, source: P, patterns: &[S]) -> Result<(), FixtureError>
     where
         P: AsRef<path::Path>,
         S: AsRef<str>,
     {
-        copy_files(self.path(), source.as_ref(), patterns)
+        copy_files(self.path(), source.as_ref(), patterns, &CopyOptions::new())?;
+        Ok(())
+    }
+}
+
+impl PathCopyWith for TempDir {
+    fn copy_from_with<P, S>(
+        &self,
+        source: P,
+        patterns: &[S],
+        options: &CopyOptions,
+    ) -> Result<CopyReport, FixtureError>
+    where
+        P: AsRef<path::Path>,
+        S: AsRef<str>,
+    {
+        copy_files(self.path(), source.as_ref(), patterns, options)
     }
 }
 
@@ -202,7 +522,166 @@ impl PathCopy for ChildPath {
         P: AsRef<path::Path>,
         S: AsRef<str>,
     {
-        copy_files(self.path(), source.as_ref(), patterns)
+        copy_files(self.path(), source.as_ref(), patterns, &CopyOptions::new())?;
+        Ok(())
+    }
+}
+
+impl PathCopyWith for ChildPath {
+    fn copy_from_with<P, S>(
+        &self,
+        source: P,
+        patterns: &[S],
+        options: &CopyOptions,
+    ) -> Result<CopyReport, FixtureError>
+    where
+        P: AsRef<path::Path>,
+        S: AsRef<str>,
+    {
+        copy_files(self.path(), source.as_ref(), patterns, options)
+    }
+}
+
+/// Remove the file or directory at [`ChildPath`].
+///
+pub trait PathRemove {
+    /// Remove the file or directory at [`ChildPath`].
+    ///
+    /// Directories are removed along with their content.  Symlinks are removed, not their target.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    /// use predicates::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let file = temp.child("subdir/foo.txt");
+    /// file.touch().unwrap();
+    ///
+    /// file.remove().unwrap();
+    /// file.assert(predicate::path::missing());
+    /// temp.child("subdir").remove().unwrap();
+    /// temp.child("subdir").assert(predicate::path::missing());
+    ///
+    /// temp.close().unwrap();
+    /// ```
+    fn remove(&self) -> Result<(), FixtureError>;
+}
+
+impl PathRemove for ChildPath {
+    fn remove(&self) -> Result<(), FixtureError> {
+        remove(self.path())
+    }
+}
+
+/// Rename or move the file or directory at [`ChildPath`].
+///
+pub trait PathRename {
+    /// Rename the file or directory at [`ChildPath`] to `target`.
+    ///
+    /// Parent directories of `target` are created as needed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    /// use predicates::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let file = temp.child("foo.txt");
+    /// file.write_str("Hello").unwrap();
+    ///
+    /// file.rename_to(temp.child("bar.txt")).unwrap();
+    /// file.assert(predicate::path::missing());
+    /// temp.child("bar.txt").assert("Hello");
+    ///
+    /// temp.close().unwrap();
+    /// ```
+    fn rename_to<P>(&self, target: P) -> Result<(), FixtureError>
+    where
+        P: AsRef<path::Path>;
+
+    /// Move the file or directory at [`ChildPath`] into the `dir` directory, keeping its name.
+    ///
+    /// `dir` is created as needed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    /// use predicates::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let file = temp.child("foo.txt");
+    /// file.write_str("Hello").unwrap();
+    ///
+    /// file.move_to(temp.child("archive")).unwrap();
+    /// file.assert(predicate::path::missing());
+    /// temp.child("archive/foo.txt").assert("Hello");
+    ///
+    /// temp.close().unwrap();
+    /// ```
+    fn move_to<P>(&self, dir: P) -> Result<(), FixtureError>
+    where
+        P: AsRef<path::Path>;
+}
+
+impl PathRename for ChildPath {
+    fn rename_to<P>(&self, target: P) -> Result<(), FixtureError>
+    where
+        P: AsRef<path::Path>,
+    {
+        rename(self.path(), target.as_ref())
+    }
+
+    fn move_to<P>(&self, dir: P) -> Result<(), FixtureError>
+    where
+        P: AsRef<path::Path>,
+    {
+        let name = self.path().file_name().ok_or_else(|| {
+            FixtureError::new(FixtureKind::Rename)
+                .with_path(dir.as_ref())
+                .with_source_path(self.path())
+        })?;
+        rename(self.path(), &dir.as_ref().join(name))
+    }
+}
+
+/// Copy the file or directory at [`ChildPath`] elsewhere.
+///
+pub trait PathCopyTo {
+    /// Copy the file or directory at [`ChildPath`] to `target`.
+    ///
+    /// Directories are copied along with their content.  Parent directories of `target` are
+    /// created as needed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let file = temp.child("subdir/foo.txt");
+    /// file.write_str("Hello").unwrap();
+    ///
+    /// temp.child("subdir").copy_to(temp.child("backup")).unwrap();
+    /// file.assert("Hello");
+    /// temp.child("backup/foo.txt").assert("Hello");
+    ///
+    /// temp.close().unwrap();
+    /// ```
+    fn copy_to<P>(&self, target: P) -> Result<(), FixtureError>
+    where
+        P: AsRef<path::Path>;
+}
+
+impl PathCopyTo for ChildPath {
+    fn copy_to<P>(&self, target: P) -> Result<(), FixtureError>
+    where
+        P: AsRef<path::Path>,
+    {
+        copy_to(self.path(), target.as_ref())
     }
 }
 
@@ -290,97 +769,260 @@ impl SymlinkToDir for TempDir {
 
 fn ensure_parent_dir(path: &path::Path) -> Result<(), FixtureError> {
     if let Some(parent) = path.parent() {
-        fs::create_dir_all(parent).chain(FixtureError::new(FixtureKind::CreateDir))?;
+        fs::create_dir_all(parent)
+            .chain(FixtureError::new(FixtureKind::CreateDir).with_path(parent))?;
     }
     Ok(())
 }
 
 fn create_dir_all(path: &path::Path) -> Result<(), FixtureError> {
-    fs::create_dir_all(path).chain(FixtureError::new(FixtureKind::CreateDir))?;
+    fs::create_dir_all(path).chain(FixtureError::new(FixtureKind::CreateDir).with_path(path))?;
     Ok(())
 }
 
 fn touch(path: &path::Path) -> Result<(), FixtureError> {
     ensure_parent_dir(path)?;
-    fs::File::create(path).chain(FixtureError::new(FixtureKind::WriteFile))?;
+    fs::File::create(path).chain(FixtureError::new(FixtureKind::WriteFile).with_path(path))?;
     Ok(())
 }
 
 fn write_binary(path: &path::Path, data: &[u8]) -> Result<(), FixtureError> {
     ensure_parent_dir(path)?;
-    let mut file = fs::File::create(path).chain(FixtureError::new(FixtureKind::WriteFile))?;
+    let mut file =
+        fs::File::create(path).chain(FixtureError::new(FixtureKind::WriteFile).with_path(path))?;
     file.write_all(data)
-        .chain(FixtureError::new(FixtureKind::WriteFile))?;
+        .chain(FixtureError::new(FixtureKind::WriteFile).with_path(path))?;
     Ok(())
 }
 
 fn write_str(path: &path::Path, data: &str) -> Result<(), FixtureError> {
+    write_binary(path, data.as_bytes())
+}
+
+fn append_binary(path: &path::Path, data: &[u8]) -> Result<(), FixtureError> {
     ensure_parent_dir(path)?;
-    write_binary(path, data.as_bytes()).chain(FixtureError::new(FixtureKind::WriteFile))
+    let mut file = fs::OpenOptions::new()
+        .append(true)
+        .create(true)
+        .open(path)
+        .chain(FixtureError::new(FixtureKind::WriteFile).with_path(path))?;
+    file.write_all(data)
+        .chain(FixtureError::new(FixtureKind::WriteFile).with_path(path))?;
+    Ok(())
+}
+
+fn read_to_string(path: &path::Path) -> Result<String, FixtureError> {
+    fs::read_to_string(path).chain(FixtureError::new(FixtureKind::ReadFile).with_path(path))
+}
+
+fn read_bytes(path: &path::Path) -> Result<Vec<u8>, FixtureError> {
+    fs::read(path).chain(FixtureError::new(FixtureKind::ReadFile).with_path(path))
+}
+
+fn read_lines(path: &path::Path) -> Result<Vec<String>, FixtureError> {
+    let content = read_to_string(path)?;
+    Ok(content.lines().map(ToOwned::to_owned).collect())
+}
+
+fn read_dir_sorted(path: &path::Path) -> Result<Vec<path::PathBuf>, FixtureError> {
+    let mut entries = fs::read_dir(path)
+        .and_then(|entries| {
+            entries
+                .map(|entry| entry.map(|entry| entry.path()))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .chain(FixtureError::new(FixtureKind::ReadDir).with_path(path))?;
+    entries.sort();
+    Ok(entries)
+}
+
+fn replace_in_file(
+    path: &path::Path,
+    pattern: &str,
+    replacement: &str,
+) -> Result<(), FixtureError> {
+    if pattern.is_empty() {
+        return Err(FixtureError::new(FixtureKind::WriteFile)
+            .with_path(path)
+            .chain(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "the pattern to replace is empty",
+            )));
+    }
+    let content = read_to_string(path)?;
+    write_str(path, &content.replace(pattern, replacement))
+}
+
+fn insert_line(path: &path::Path, index: usize, line: &str) -> Result<(), FixtureError> {
+    let content = read_to_string(path)?;
+    let eol = if content.contains("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    };
+    let mut lines: Vec<_> = content
+        .split_inclusive('\n')
+        .map(ToOwned::to_owned)
+        .collect();
+    if lines.len() < index {
+        return Err(line_out_of_range(path, index, lines.len()));
+    }
+    if index == lines.len() {
+        if let Some(last) = lines.last_mut() {
+            if !last.ends_with('\n') {
+                last.push_str(eol);
+            }
+        }
+    }
+    lines.insert(index, format!("{line}{eol}"));
+    write_str(path, &lines.concat())
+}
+
+fn delete_line(path: &path::Path, index: usize) -> Result<(), FixtureError> {
+    let content = read_to_string(path)?;
+    let mut lines: Vec<_> = content.split_inclusive('\n').collect();
+    if lines.len() <= index {
+        return Err(line_out_of_range(path, index, lines.len()));
+    }
+    lines.remove(index);
+    write_str(path, &lines.concat())
+}
+
+fn line_out_of_range(path: &path::Path, index: usize, len: usize) -> FixtureError {
+    FixtureError::new(FixtureKind::WriteFile)
+        .with_path(path)
+        .chain(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("line {index} is out of range for a file with {len} lines"),
+        ))
 }
 
 fn write_file(path: &path::Path, data: &path::Path) -> Result<(), FixtureError> {
     ensure_parent_dir(path)?;
-    fs::copy(data, path).chain(FixtureError::new(FixtureKind::CopyFile))?;
+    fs::copy(data, path).chain(
+        FixtureError::new(FixtureKind::CopyFile)
+            .with_path(path)
+            .with_source_path(data),
+    )?;
     Ok(())
 }
 
-fn copy_files<S>(
-    target: &path::Path,
-    source: &path::Path,
-    patterns: &[S],
-) -> Result<(), FixtureError>
-where
-    S: AsRef<str>,
-{
-    // `walkdir`, on Windows, seems to convert "." into "" which then fails.
-    let source = source
-        .canonicalize()
-        .chain(FixtureError::new(FixtureKind::Walk))?;
-    for entry in globwalk::GlobWalkerBuilder::from_patterns(&source, patterns)
-        .follow_links(true)
-        .build()
-        .chain(FixtureError::new(FixtureKind::Walk))?
-    {
-        let entry = entry.chain(FixtureError::new(FixtureKind::Walk))?;
-        let rel = entry
-            .path()
-            .strip_prefix(&source)
-            .expect("entries to be under `source`");
-        let target_path = target.join(rel);
-        if entry.file_type().is_dir() {
-            fs::create_dir_all(target_path).chain(FixtureError::new(FixtureKind::CreateDir))?;
-        } else if entry.file_type().is_file() {
-            fs::create_dir_all(target_path.parent().expect("at least `target` exists"))
-                .chain(FixtureError::new(FixtureKind::CreateDir))?;
-            fs::copy(entry.path(), target_path).chain(FixtureError::new(FixtureKind::CopyFile))?;
-        }
+fn remove(path: &path::Path) -> Result<(), FixtureError> {
+    let metadata = path
+        .symlink_metadata()
+        .chain(FixtureError::new(FixtureKind::Remove).with_path(path))?;
+    if metadata.is_dir() {
+        fs::remove_dir_all(path)
+    } else if is_symlink_dir(&metadata.file_type()) {
+        fs::remove_dir(path)
+    } else {
+        fs::remove_file(path)
     }
+    .chain(FixtureError::new(FixtureKind::Remove).with_path(path))?;
+    Ok(())
+}
+
+fn rename(source: &path::Path, target: &path::Path) -> Result<(), FixtureError> {
+    ensure_parent_dir(target)?;
+    fs::rename(source, target).chain(
+        FixtureError::new(FixtureKind::Rename)
+            .with_path(target)
+            .with_source_path(source),
+    )?;
     Ok(())
 }
 
+fn copy_to(source: &path::Path, target: &path::Path) -> Result<(), FixtureError> {
+    if source.is_dir() {
+        create_dir_all(target)?;
+        copy_files(target, source, &["**"], &CopyOptions::new())?;
+        Ok(())
+    } else {
+        write_file(target, source)
+    }
+}
+
+fn symlink_error(link: &path::Path, target: &path::Path) -> FixtureError {
+    FixtureError::new(FixtureKind::Symlink)
+        .with_path(link)
+        .with_source_path(target)
+}
+
 #[cfg(windows)]
 fn symlink_to_file(link: &path::Path, target: &path::Path) -> Result<(), FixtureError> {
-    std::os::windows::fs::symlink_file(target, link)
-        .chain(FixtureError::new(FixtureKind::Symlink))?;
+    std::os::windows::fs::symlink_file(target, link).chain(symlink_error(link, target))?;
     Ok(())
 }
 
 #[cfg(windows)]
 fn symlink_to_dir(link: &path::Path, target: &path::Path) -> Result<(), FixtureError> {
-    std::os::windows::fs::symlink_dir(target, link)
-        .chain(FixtureError::new(FixtureKind::Symlink))?;
+    std::os::windows::fs::symlink_dir(target, link).chain(symlink_error(link, target))?;
     Ok(())
 }
 
+#[cfg(windows)]
+fn is_symlink_dir(file_type: &fs::FileType) -> bool {
+    use std::os::windows::fs::FileTypeExt as _;
+    file_type.is_symlink_dir()
+}
+
 #[cfg(not(windows))]
 fn symlink_to_file(link: &path::Path, target: &path::Path) -> Result<(), FixtureError> {
-    std::os::unix::fs::symlink(target, link).chain(FixtureError::new(FixtureKind::Symlink))?;
+    std::os::unix::fs::symlink(target, link).chain(symlink_error(link, target))?;
     Ok(())
 }
 
 #[cfg(not(windows))]
 fn symlink_to_dir(link: &path::Path, target: &path::Path) -> Result<(), FixtureError> {
-    std::os::unix::fs::symlink(target, link).chain(FixtureError::new(FixtureKind::Symlink))?;
+    std::os::unix::fs::symlink(target, link).chain(symlink_error(link, target))?;
     Ok(())
 }
+
+#[cfg(not(windows))]
+fn is_symlink_dir(_file_type: &fs::FileType) -> bool {
+    false
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::fixture::PathChild;
+    use crate::fixture::TempDir;
+
+    #[test]
+    fn remove_symlink_to_dir() {
+        let temp = TempDir::new().unwrap();
+        let real_dir = temp.child("real_dir");
+        real_dir.child("foo.txt").touch().unwrap();
+        let link = temp.child("link_dir");
+        link.symlink_to_dir(real_dir.path()).unwrap();
+
+        link.remove().unwrap();
+        assert!(link.symlink_metadata().is_err());
+        assert!(real_dir.child("foo.txt").exists());
+    }
+
+    #[test]
+    fn replace_empty_pattern() {
+        let temp = TempDir::new().unwrap();
+        let file = temp.child("foo.txt");
+        file.write_str("foo").unwrap();
+
+        let err = file.replace_in_file("", "bar").unwrap_err();
+        assert_eq!(err.kind(), FixtureKind::WriteFile);
+        assert_eq!(err.io_error_kind(), Some(std::io::ErrorKind::InvalidInput));
+        assert_eq!(fs::read_to_string(file.path()).unwrap(), "foo");
+    }
+
+    #[test]
+    fn read_dir_sorted_missing() {
+        let temp = TempDir::new().unwrap();
+        let missing = temp.child("missing");
+
+        let err = missing.read_dir_sorted().unwrap_err();
+        assert_eq!(err.kind(), FixtureKind::ReadDir);
+        assert_eq!(err.path(), Some(missing.path()));
+        assert_eq!(err.io_error_kind(), Some(std::io::ErrorKind::NotFound));
+    }
+}
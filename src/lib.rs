@@ -12,12 +12,38 @@
 //! - [`write_binary`][`FileWriteBin`] a [`ChildPath`] or [`NamedTempFile`]
 //! - [`write_str`][`FileWriteStr`] a [`ChildPath`] or [`NamedTempFile`]
 //! - [`write_file`][`FileWriteFile`] a [`ChildPath`] or [`NamedTempFile`]
-//! - [`copy_from`][`PathCopy`] a pristine folder to a [`ChildPath`] or [`TempDir`]
+//! - `write_json` or `write_toml` a [`ChildPath`] or [`NamedTempFile`] (requires the `serde`
+//!   feature)
+//! - [`append_binary`][`FileAppendBin`] or [`append_str`][`FileAppendStr`] to a [`ChildPath`] or
+//!   [`NamedTempFile`]
+//! - [`replace_in_file`][`FileEdit`] or insert and delete lines in a [`ChildPath`] or
+//!   [`NamedTempFile`]
+//! - [`truncate`][`FileCorrupt`] or otherwise damage a [`ChildPath`] or [`NamedTempFile`]
+//! - [`copy_from`][`PathCopy`] or [`copy_from_with`][`PathCopyWith`] a pristine folder to a
+//!   [`ChildPath`] or [`TempDir`]
+//! - [`copy`][`CachedFixture`] an expensive fixture, built only once, to a [`TempDir`]
 //! - [`symlink_to_file`][`SymlinkToFile`] a [`ChildPath`] or [`NamedTempFile`]
 //! - [`symlink_to_dir`][`SymlinkToDir`] a [`ChildPath`] or [`TempDir`]
+//! - [`remove`][`PathRemove`], [`rename_to`][`PathRename`], or [`copy_to`][`PathCopyTo`] a
+//!   [`ChildPath`]
 //!
 //! Validating
 //! - [`assert`][`PathAssert`] a [`ChildPath`], [`TempDir`], or [`NamedTempFile`]
+//! - [`assert_redacted`][`PathAssertRedacted`] a [`ChildPath`] or [`TempDir`], ignoring where the
+//!   [`TempDir`] is
+//! - [`assert_each`][`PathAssertEach`] file matching a glob in a [`ChildPath`] or [`TempDir`]
+//! - [`read_to_string`][`FileRead`] a [`ChildPath`] or [`NamedTempFile`] to post-process it
+//! - `read_json` or `read_toml` a [`ChildPath`] or [`NamedTempFile`] (requires the `serde`
+//!   feature)
+//! - [`read_dir_sorted`][`PathReadDir`] a [`ChildPath`] or [`TempDir`]
+//! - Compare text with wildcards and redactions using [`wildcard_eq`]
+//! - Compare binary content, reporting differences as a hexdump, using [`bytes::eq`]
+//! - Compare large files in chunks, without reading them into memory, using [`file::eq_str`] or
+//!   [`file::eq_file`]
+//! - Check that a directory [`dir::is_empty`], or [`dir::contains_exactly`] the expected files
+//! - Check a whole directory against the expected [`dir::tree`], with a predicate per file
+//! - Compare JSON or TOML content structurally with `predicate::json` or `predicate::toml`
+//!   (requires the `serde` feature), or YAML with `predicate::yaml` (requires the `yaml` feature)
 //!
 //! ## Example
 //!
@@ -41,13 +67,33 @@
 //!
 //! [`ChildPath`]: fixture::ChildPath
 //! [`FileTouch`]: fixture::FileTouch
+//! [`FileAppendBin`]: fixture::FileAppendBin
+//! [`FileAppendStr`]: fixture::FileAppendStr
+//! [`FileEdit`]: fixture::FileEdit
+//! [`FileCorrupt`]: fixture::FileCorrupt
+//! [`FileRead`]: fixture::FileRead
 //! [`FileWriteBin`]: fixture::FileWriteBin
 //! [`FileWriteStr`]: fixture::FileWriteStr
 //! [`FileWriteFile`]: fixture::FileWriteFile
 //! [`SymlinkToDir`]: fixture::SymlinkToDir
 //! [`SymlinkToFile`]: fixture::SymlinkToFile
 //! [`PathCopy`]: fixture::PathCopy
+//! [`PathCopyWith`]: fixture::PathCopyWith
+//! [`PathCopyTo`]: fixture::PathCopyTo
+//! [`PathReadDir`]: fixture::PathReadDir
+//! [`PathRemove`]: fixture::PathRemove
+//! [`PathRename`]: fixture::PathRename
+//! [`CachedFixture`]: fixture::CachedFixture
 //! [`PathAssert`]: assert::PathAssert
+//! [`PathAssertRedacted`]: assert::PathAssertRedacted
+//! [`PathAssertEach`]: assert::PathAssertEach
+//! [`wildcard_eq`]: predicate::str::wildcard_eq
+//! [`bytes::eq`]: predicate::bytes::eq
+//! [`file::eq_str`]: predicate::file::eq_str
+//! [`file::eq_file`]: predicate::file::eq_file
+//! [`dir::is_empty`]: predicate::dir::is_empty
+//! [`dir::contains_exactly`]: predicate::dir::contains_exactly
+//! [`dir::tree`]: predicate::dir::tree
 //! [dir-diff]: https://crates.io/crates/dir-diff
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
@@ -56,6 +102,7 @@
 
 pub mod assert;
 pub mod fixture;
+pub mod predicate;
 
 // Pulling this in for convenience-sake
 #[doc(inline)]
@@ -68,13 +115,29 @@ pub use crate::fixture::NamedTempFile;
 /// Extension traits that are useful to have available.
 pub mod prelude {
     pub use crate::assert::PathAssert;
+    pub use crate::assert::PathAssertEach;
+    pub use crate::assert::PathAssertRedacted;
+    pub use crate::fixture::FileAppendBin;
+    pub use crate::fixture::FileAppendStr;
+    pub use crate::fixture::FileCorrupt;
+    pub use crate::fixture::FileEdit;
+    #[cfg(feature = "serde")]
+    pub use crate::fixture::FileJson;
+    pub use crate::fixture::FileRead;
+    #[cfg(feature = "serde")]
+    pub use crate::fixture::FileToml;
     pub use crate::fixture::FileTouch;
     pub use crate::fixture::FileWriteBin;
     pub use crate::fixture::FileWriteFile;
     pub use crate::fixture::FileWriteStr;
     pub use crate::fixture::PathChild;
     pub use crate::fixture::PathCopy;
+    pub use crate::fixture::PathCopyTo;
+    pub use crate::fixture::PathCopyWith;
     pub use crate::fixture::PathCreateDir;
+    pub use crate::fixture::PathReadDir;
+    pub use crate::fixture::PathRemove;
+    pub use crate::fixture::PathRename;
     pub use crate::fixture::SymlinkToDir;
     pub use crate::fixture::SymlinkToFile;
 }
@@ -0,0 +1,267 @@
+//! Binary file content predicates.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use assert_fs::prelude::*;
+//! use assert_fs::predicate::bytes;
+//!
+//! let temp = assert_fs::TempDir::new().unwrap();
+//! let image = temp.child("image.bin");
+//! image.write_binary(&[0x89, b'P', b'N', b'G']).unwrap();
+//!
+//! image.assert(bytes::eq(b"\x89PNG".as_slice()));
+//!
+//! temp.close().unwrap();
+//! ```
+
+use std::borrow::Cow;
+use std::fmt;
+use std::fmt::Write as _;
+use std::io;
+use std::ops::Range;
+use std::path;
+
+use predicates_core::reflection;
+
+use super::file;
+
+/// Bytes shown per row of a hexdump.
+pub(crate) const ROW_LEN: usize = 8;
+/// Rows shown around a difference.
+const CONTEXT_ROWS: usize = 1;
+/// Rows shown per difference, before truncating it.
+const MAX_WINDOW_ROWS: usize = 8;
+/// Differences shown, before truncating the rest.
+const MAX_WINDOWS: usize = 4;
+/// The most bytes a hexdump shows.
+pub(crate) const HEXDUMP_LEN: usize = MAX_WINDOWS * MAX_WINDOW_ROWS * ROW_LEN;
+
+/// Creates a new predicate that compares a file's content to `expected`.
+///
+/// The file is compared in chunks, stopping at the first difference.  On failure, this reports the
+/// offset of the first difference, both lengths, and a side-by-side hexdump around each difference
+/// near it.
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+/// use assert_fs::predicate::bytes;
+/// use predicates::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// let output = temp.child("output.bin");
+/// output.write_binary(&[0, 1, 2, 3]).unwrap();
+///
+/// output.assert(bytes::eq(vec![0, 1, 2, 3]));
+/// output.assert(bytes::eq(vec![0, 1, 2, 4]).not());
+///
+/// temp.close().unwrap();
+/// ```
+pub fn eq(expected: impl Into<Cow<'static, [u8]>>) -> BytesEqPredicate {
+    BytesEqPredicate {
+        expected: expected.into(),
+    }
+}
+
+/// Predicate that compares a file's content, reporting differences as a hexdump.
+///
+/// This is created by [`eq`].
+#[derive(Debug, Clone)]
+pub struct BytesEqPredicate {
+    expected: Cow<'static, [u8]>,
+}
+
+impl reflection::PredicateReflection for BytesEqPredicate {}
+
+impl predicates_core::Predicate<path::Path> for BytesEqPredicate {
+    fn eval(&self, variable: &path::Path) -> bool {
+        file::is_eq(io::Cursor::new(&*self.expected), variable)
+    }
+
+    fn find_case<'a>(
+        &'a self,
+        expected: bool,
+        variable: &path::Path,
+    ) -> Option<reflection::Case<'a>> {
+        file::find_case(
+            self,
+            expected,
+            io::Cursor::new(&*self.expected),
+            variable,
+            file::Format::Binary,
+        )
+    }
+}
+
+impl fmt::Display for BytesEqPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let palette = crate::Palette::color();
+        write!(
+            f,
+            "{:#} {:#}",
+            palette.key("var =="),
+            palette.value(format!("{} bytes", self.expected.len()))
+        )
+    }
+}
+
+/// The offset of the first byte that differs, including where one is longer than the other.
+pub(crate) fn first_difference(expected: &[u8], actual: &[u8]) -> Option<usize> {
+    expected
+        .iter()
+        .zip(actual)
+        .position(|(e, a)| e != a)
+        .or_else(|| (expected.len() != actual.len()).then(|| expected.len().min(actual.len())))
+}
+
+/// The offset to start a hexdump of the difference at `offset`.
+pub(crate) fn hexdump_start(offset: u64) -> u64 {
+    let row = offset / ROW_LEN as u64;
+    row.saturating_sub(CONTEXT_ROWS as u64) * ROW_LEN as u64
+}
+
+/// A side-by-side hexdump of the rows around each difference.
+///
+/// `expected` and `actual` start at `offset`, which is a multiple of [`ROW_LEN`].
+pub(crate) fn hexdump(offset: u64, expected: &[u8], actual: &[u8]) -> String {
+    let rows = expected.len().max(actual.len()).div_ceil(ROW_LEN);
+    let differs = |row: usize| {
+        let range = row * ROW_LEN..(row + 1) * ROW_LEN;
+        get(expected, range.clone()) != get(actual, range)
+    };
+
+    // Group differing rows that are close enough to share their context
+    let mut windows: Vec<Range<usize>> = Vec::new();
+    for row in (0..rows).filter(|row| differs(*row)) {
+        let start = row.saturating_sub(CONTEXT_ROWS);
+        let end = (row + 1 + CONTEXT_ROWS).min(rows);
+        match windows.last_mut() {
+            Some(last) if start <= last.end => last.end = end,
+            _ => windows.push(start..end),
+        }
+    }
+
+    let mut out = String::from("\n");
+    let skipped = windows.len().saturating_sub(MAX_WINDOWS);
+    for (i, window) in windows.into_iter().take(MAX_WINDOWS).enumerate() {
+        if i != 0 {
+            out.push_str("...\n");
+        }
+        let shown = window.start..window.end.min(window.start + MAX_WINDOW_ROWS);
+        let start = shown.start * ROW_LEN;
+        let end = shown.end * ROW_LEN;
+        render(
+            &mut out,
+            offset + start as u64,
+            get(expected, start..end),
+            get(actual, start..end),
+        );
+        if shown.end < window.end {
+            let _ = writeln!(out, "... {} more rows", window.end - shown.end);
+        }
+    }
+    if skipped != 0 {
+        let _ = writeln!(out, "... {skipped} more differences");
+    }
+    out
+}
+
+/// Render rows of `expected` and `actual` side-by-side, starting at `offset`.
+///
+/// Rows that differ are marked with `>`.
+fn render(out: &mut String, offset: u64, expected: &[u8], actual: &[u8]) {
+    let rows = expected.len().max(actual.len()).div_ceil(ROW_LEN);
+    for row in 0..rows {
+        let range = row * ROW_LEN..(row + 1) * ROW_LEN;
+        let expected = get(expected, range.clone());
+        let actual = get(actual, range);
+        let marker = if expected == actual { ' ' } else { '>' };
+        let line = format!(
+            "{marker} {:08x}  {} | {}",
+            offset + (row * ROW_LEN) as u64,
+            render_row(expected),
+            render_row(actual)
+        );
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+}
+
+fn render_row(row: &[u8]) -> String {
+    let mut out = String::new();
+    for i in 0..ROW_LEN {
+        match row.get(i) {
+            Some(byte) => {
+                let _ = write!(out, "{byte:02x} ");
+            }
+            None => out.push_str("   "),
+        }
+    }
+    out.push(' ');
+    for i in 0..ROW_LEN {
+        out.push(match row.get(i) {
+            Some(byte) if byte.is_ascii_graphic() || *byte == b' ' => char::from(*byte),
+            Some(_) => '.',
+            None => ' ',
+        });
+    }
+    out
+}
+
+fn get(bytes: &[u8], range: Range<usize>) -> &[u8] {
+    let start = range.start.min(bytes.len());
+    let end = range.end.min(bytes.len());
+    &bytes[start..end]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn first_differences() {
+        assert_eq!(first_difference(b"abc", b"abc"), None);
+        assert_eq!(first_difference(b"abc", b"abd"), Some(2));
+        assert_eq!(first_difference(b"abc", b"ab"), Some(2));
+        assert_eq!(first_difference(b"", b"a"), Some(0));
+    }
+
+    #[test]
+    fn hexdump_windows() {
+        let expected: Vec<u8> = (0..64).collect();
+        let mut actual = expected.clone();
+        actual[1] = 0xff;
+        actual[50] = b'A';
+        actual.truncate(60);
+
+        assert_eq!(
+            hexdump(0, &expected, &actual),
+            "
+> 00000000  00 01 02 03 04 05 06 07  ........ | 00 ff 02 03 04 05 06 07  ........
+  00000008  08 09 0a 0b 0c 0d 0e 0f  ........ | 08 09 0a 0b 0c 0d 0e 0f  ........
+...
+  00000028  28 29 2a 2b 2c 2d 2e 2f  ()*+,-./ | 28 29 2a 2b 2c 2d 2e 2f  ()*+,-./
+> 00000030  30 31 32 33 34 35 36 37  01234567 | 30 31 41 33 34 35 36 37  01A34567
+> 00000038  38 39 3a 3b 3c 3d 3e 3f  89:;<=>? | 38 39 3a 3b              89:;
+"
+        );
+    }
+
+    #[test]
+    fn hexdump_truncates() {
+        let expected = vec![0; 1024];
+        let actual = vec![1; 1024];
+        let dump = hexdump(0, &expected, &actual);
+        assert_eq!(dump.lines().count(), 1 + MAX_WINDOW_ROWS + 1, "{dump}");
+        assert!(dump.ends_with("... 120 more rows\n"), "{dump}");
+
+        let mut actual = expected.clone();
+        for i in (0..1024).step_by(64) {
+            actual[i] = 1;
+        }
+        let dump = hexdump(0, &expected, &actual);
+        assert!(dump.ends_with("... 12 more differences\n"), "{dump}");
+    }
+}
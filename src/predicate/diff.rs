@@ -0,0 +1,89 @@
+//! Render differences between expected and actual content.
+
+/// A unified diff of the lines of `expected` and `actual`, for a [`Product`].
+///
+/// [`Product`]: predicates_core::reflection::Product
+pub(crate) fn unified(expected: &str, actual: &str) -> String {
+    unified_at(1, expected, actual)
+}
+
+/// A unified diff of the lines of `expected` and `actual`, which start at line `first_line` of
+/// their content.
+pub(crate) fn unified_at(first_line: u64, expected: &str, actual: &str) -> String {
+    render(crate::Palette::color(), first_line, expected, actual)
+}
+
+fn render(palette: crate::Palette, first_line: u64, expected: &str, actual: &str) -> String {
+    let expected: Vec<_> = expected.lines().map(|l| format!("{l}\n")).collect();
+    let actual: Vec<_> = actual.lines().map(|l| format!("{l}\n")).collect();
+    let diff = difflib::unified_diff(&expected, &actual, "", "", "expected", "actual", 0);
+    let mut out = String::from("\n");
+    for (i, line) in diff.iter().enumerate() {
+        match (i, line.as_bytes().first()) {
+            (0 | 1, _) => out.push_str(line),
+            (_, Some(b'@')) => out.push_str(&shift_hunk(line, first_line - 1)),
+            (_, Some(b'-')) => {
+                let (prefix, body) = line.split_at(1);
+                out.push_str(&format!("{:#}{body}", palette.key(prefix)));
+            }
+            (_, Some(b'+')) => {
+                let (prefix, body) = line.split_at(1);
+                out.push_str(&format!("{:#}{body}", palette.value(prefix)));
+            }
+            _ => out.push_str(line),
+        }
+    }
+    out
+}
+
+/// Shift the line numbers of a hunk header, like `@@ -3,2 +3 @@`, by `offset`.
+fn shift_hunk(header: &str, offset: u64) -> String {
+    if offset == 0 {
+        return header.to_owned();
+    }
+    let parts: Vec<_> = header
+        .split(' ')
+        .map(|part| {
+            let Some(range) = part.strip_prefix(['-', '+']) else {
+                return part.to_owned();
+            };
+            let (start, len) = range
+                .split_once(',')
+                .map(|(start, len)| (start, Some(len)))
+                .unwrap_or((range, None));
+            let Ok(start) = start.parse::<u64>() else {
+                return part.to_owned();
+            };
+            let sign = &part[..1];
+            match len {
+                Some(len) => format!("{sign}{},{len}", start + offset),
+                None => format!("{sign}{}", start + offset),
+            }
+        })
+        .collect();
+    parts.join(" ")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn hunks() {
+        let palette = crate::Palette::plain();
+        assert_eq!(render(palette, 1, "a\nb\nc\n", "a\nb\nc\n"), "\n");
+        assert_eq!(
+            render(palette, 1, "a\nb\nc\nd\n", "a\nB\nc\nd\ne\n"),
+            "
+--- \texpected
++++ \tactual
+@@ -2 +2 @@
+-b
++B
+@@ -4,0 +5 @@
++e
+"
+        );
+        assert!(render(palette, 41, "a\nb\n", "a\n").ends_with("@@ -42 +41,0 @@\n-b\n"));
+    }
+}
@@ -0,0 +1,770 @@
+//! Directory content predicates.
+//!
+//! On failure, these show the directory's content as a tree.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use assert_fs::prelude::*;
+//! use assert_fs::predicate::dir;
+//!
+//! let temp = assert_fs::TempDir::new().unwrap();
+//! temp.child("a.txt").touch().unwrap();
+//! temp.child("sub/b.rs").touch().unwrap();
+//!
+//! temp.assert(dir::contains(["a.txt", "sub/b.rs"]));
+//! temp.assert(dir::contains_exactly(["*.txt", "sub/*.rs"]));
+//! temp.child("empty").create_dir_all().unwrap();
+//! temp.child("empty").assert(dir::is_empty());
+//!
+//! temp.close().unwrap();
+//! ```
+
+use std::fmt;
+use std::fs;
+use std::path;
+
+use predicates::BoxPredicate;
+use predicates::prelude::PredicateBoxExt;
+use predicates_core::reflection;
+
+use crate::assert::IntoPathPredicate;
+use crate::fixture::walk_root;
+
+/// Entries shown in a tree, before truncating the rest.
+const MAX_TREE_ENTRIES: usize = 100;
+
+/// Creates a new predicate that ensures a directory has no entries.
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+/// use assert_fs::predicate::dir;
+/// use predicates::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// temp.assert(dir::is_empty());
+///
+/// temp.child("a.txt").touch().unwrap();
+/// temp.assert(dir::is_empty().not());
+///
+/// temp.close().unwrap();
+/// ```
+pub fn is_empty() -> IsEmptyPredicate {
+    IsEmptyPredicate {}
+}
+
+/// Predicate that ensures a directory has no entries.
+///
+/// This is created by [`is_empty`].
+#[derive(Debug, Clone)]
+pub struct IsEmptyPredicate {}
+
+impl IsEmptyPredicate {
+    fn mismatches(&self, path: &path::Path) -> Result<Vec<reflection::Product>, String> {
+        let mut entries = fs::read_dir(path).map_err(|err| err.to_string())?;
+        if entries.next().is_none() {
+            return Ok(Vec::new());
+        }
+        Ok(vec![reflection::Product::new(
+            "entries",
+            count_entries(path)?,
+        )])
+    }
+}
+
+impl reflection::PredicateReflection for IsEmptyPredicate {}
+
+impl predicates_core::Predicate<path::Path> for IsEmptyPredicate {
+    fn eval(&self, variable: &path::Path) -> bool {
+        matches!(self.mismatches(variable), Ok(m) if m.is_empty())
+    }
+
+    fn find_case<'a>(
+        &'a self,
+        expected: bool,
+        variable: &path::Path,
+    ) -> Option<reflection::Case<'a>> {
+        find_case(self, expected, variable, self.mismatches(variable))
+    }
+}
+
+impl fmt::Display for IsEmptyPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let palette = crate::Palette::color();
+        write!(f, "{:#} is empty", palette.key("var"))
+    }
+}
+
+/// Creates a new predicate that ensures a directory contains each of `paths`.
+///
+/// `paths` are relative to the directory, and may be files, directories, or symlinks.
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+/// use assert_fs::predicate::dir;
+/// use predicates::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// temp.child("sub/b.rs").touch().unwrap();
+///
+/// temp.assert(dir::contains(["sub", "sub/b.rs"]));
+/// temp.assert(dir::contains(["a.txt"]).not());
+///
+/// temp.close().unwrap();
+/// ```
+pub fn contains<I, P>(paths: I) -> ContainsPredicate
+where
+    I: IntoIterator<Item = P>,
+    P: Into<path::PathBuf>,
+{
+    ContainsPredicate {
+        paths: paths.into_iter().map(Into::into).collect(),
+    }
+}
+
+/// Predicate that ensures a directory contains some paths.
+///
+/// This is created by [`contains`].
+#[derive(Debug, Clone)]
+pub struct ContainsPredicate {
+    paths: Vec<path::PathBuf>,
+}
+
+impl ContainsPredicate {
+    fn mismatches(&self, path: &path::Path) -> Result<Vec<reflection::Product>, String> {
+        if !path.is_dir() {
+            return Err(format!("{} is not a directory", path.display()));
+        }
+        Ok(self
+            .paths
+            .iter()
+            .filter(|p| fs::symlink_metadata(path.join(p)).is_err())
+            .map(|p| reflection::Product::new("missing", p.display().to_string()))
+            .collect())
+    }
+}
+
+impl reflection::PredicateReflection for ContainsPredicate {}
+
+impl predicates_core::Predicate<path::Path> for ContainsPredicate {
+    fn eval(&self, variable: &path::Path) -> bool {
+        matches!(self.mismatches(variable), Ok(m) if m.is_empty())
+    }
+
+    fn find_case<'a>(
+        &'a self,
+        expected: bool,
+        variable: &path::Path,
+    ) -> Option<reflection::Case<'a>> {
+        find_case(self, expected, variable, self.mismatches(variable))
+    }
+}
+
+impl fmt::Display for ContainsPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let palette = crate::Palette::color();
+        let paths: Vec<_> = self
+            .paths
+            .iter()
+            .map(|p| format!("`{}`", p.display()))
+            .collect();
+        write!(
+            f,
+            "{:#} contains {:#}",
+            palette.key("var"),
+            palette.value(paths.join(", "))
+        )
+    }
+}
+
+/// Creates a new predicate that ensures a directory's files are those matched by `globs`.
+///
+/// Every file, searched recursively, must match a glob, and every glob must match a file.
+/// Patterns are matched like [`PathCopy::copy_from`][crate::fixture::PathCopy::copy_from], so
+/// negated patterns, like `!*.tmp`, exclude files from those the other globs match.
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+/// use assert_fs::predicate::dir;
+/// use predicates::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// temp.child("Cargo.toml").touch().unwrap();
+/// temp.child("src/lib.rs").touch().unwrap();
+///
+/// temp.assert(dir::contains_exactly(["Cargo.toml", "src/*.rs"]));
+/// temp.assert(dir::contains_exactly(["src/*.rs"]).not());
+/// temp.assert(dir::contains_exactly(["Cargo.*", "src/*.rs", "README.md"]).not());
+///
+/// temp.close().unwrap();
+/// ```
+pub fn contains_exactly<I, S>(globs: I) -> ContainsExactlyPredicate
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    ContainsExactlyPredicate {
+        globs: globs.into_iter().map(Into::into).collect(),
+    }
+}
+
+/// Predicate that ensures a directory's files are exactly those matched by globs.
+///
+/// This is created by [`contains_exactly`].
+#[derive(Debug, Clone)]
+pub struct ContainsExactlyPredicate {
+    globs: Vec<String>,
+}
+
+impl ContainsExactlyPredicate {
+    fn mismatches(&self, path: &path::Path) -> Result<Vec<reflection::Product>, String> {
+        let root = walk_root(path).map_err(|err| err.to_string())?;
+        if !root.is_dir() {
+            return Err(format!("{} is not a directory", path.display()));
+        }
+        let matched = files(&root, &self.globs)?;
+        let mut mismatches = Vec::new();
+        for glob in self.globs.iter().filter(|glob| !glob.starts_with('!')) {
+            if files(&root, &[glob])?.is_empty() {
+                mismatches.push(reflection::Product::new("unmatched", glob.clone()));
+            }
+        }
+        for file in files(&root, &["**"])? {
+            if matched.binary_search(&file).is_err() {
+                mismatches.push(reflection::Product::new("unexpected", file));
+            }
+        }
+        Ok(mismatches)
+    }
+}
+
+impl reflection::PredicateReflection for ContainsExactlyPredicate {}
+
+impl predicates_core::Predicate<path::Path> for ContainsExactlyPredicate {
+    fn eval(&self, variable: &path::Path) -> bool {
+        matches!(self.mismatches(variable), Ok(m) if m.is_empty())
+    }
+
+    fn find_case<'a>(
+        &'a self,
+        expected: bool,
+        variable: &path::Path,
+    ) -> Option<reflection::Case<'a>> {
+        find_case(self, expected, variable, self.mismatches(variable))
+    }
+}
+
+impl fmt::Display for ContainsExactlyPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let palette = crate::Palette::color();
+        let globs: Vec<_> = self.globs.iter().map(|g| format!("`{g}`")).collect();
+        write!(
+            f,
+            "{:#} contains exactly {:#}",
+            palette.key("var"),
+            palette.value(globs.join(", "))
+        )
+    }
+}
+
+/// Creates a new predicate that checks the number of entries directly within a directory.
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+/// use assert_fs::predicate::dir;
+/// use predicates::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// temp.child("a.txt").touch().unwrap();
+/// temp.child("sub/b.rs").touch().unwrap();
+///
+/// temp.assert(dir::entry_count(predicate::eq(2)));
+/// temp.assert(dir::entry_count(predicate::gt(2)).not());
+///
+/// temp.close().unwrap();
+/// ```
+pub fn entry_count<P>(pred: P) -> EntryCountPredicate<P>
+where
+    P: predicates_core::Predicate<usize>,
+{
+    EntryCountPredicate { pred }
+}
+
+/// Predicate that checks the number of entries directly within a directory.
+///
+/// This is created by [`entry_count`].
+#[derive(Debug, Clone)]
+pub struct EntryCountPredicate<P> {
+    pred: P,
+}
+
+impl<P> reflection::PredicateReflection for EntryCountPredicate<P>
+where
+    P: predicates_core::Predicate<usize>,
+{
+    fn children<'a>(&'a self) -> Box<dyn Iterator<Item = reflection::Child<'a>> + 'a> {
+        let params = vec![reflection::Child::new("predicate", &self.pred)];
+        Box::new(params.into_iter())
+    }
+}
+
+impl<P> predicates_core::Predicate<path::Path> for EntryCountPredicate<P>
+where
+    P: predicates_core::Predicate<usize>,
+{
+    fn eval(&self, variable: &path::Path) -> bool {
+        count_entries(variable)
+            .map(|count| self.pred.eval(&count))
+            .unwrap_or(false)
+    }
+
+    fn find_case<'a>(
+        &'a self,
+        expected: bool,
+        variable: &path::Path,
+    ) -> Option<reflection::Case<'a>> {
+        let count = match count_entries(variable) {
+            Ok(count) => count,
+            Err(err) => return find_case(self, expected, variable, Err(err)),
+        };
+        let child = self.pred.find_case(expected, &count)?;
+        Some(
+            reflection::Case::new(Some(self), expected)
+                .add_child(child)
+                .add_product(reflection::Product::new("entries", count))
+                .add_product(reflection::Product::new("contents", contents(variable))),
+        )
+    }
+}
+
+impl<P> fmt::Display for EntryCountPredicate<P>
+where
+    P: predicates_core::Predicate<usize>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let palette = crate::Palette::color();
+        write!(f, "{:#}: {}", palette.key("entry count"), self.pred)
+    }
+}
+
+/// Creates a new predicate that checks a directory against the expected tree of entries.
+///
+/// Each entry is checked with its own predicate, and every failing entry is reported.  Use
+/// [`TreePredicate::forbid_extra`] to also fail on files that aren't in the tree.
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+/// use assert_fs::predicate::dir;
+/// use predicates::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// temp.child("README.md").write_str("# Hello\n").unwrap();
+/// temp.child("src/lib.rs").write_str("pub fn hello() {}\n").unwrap();
+/// temp.child("target/debug/hello").touch().unwrap();
+///
+/// temp.assert(
+///     dir::tree()
+///         .file("README.md", "# Hello\n")
+///         .file("src/lib.rs", predicate::str::contains("fn hello"))
+///         .exists("target")
+///         .forbid_extra(),
+/// );
+/// temp.assert(dir::tree().file("src/main.rs", "").not());
+///
+/// temp.close().unwrap();
+/// ```
+pub fn tree() -> TreePredicate {
+    TreePredicate {
+        entries: Vec::new(),
+        forbid_extra: false,
+    }
+}
+
+/// Predicate that checks a directory against an expected tree of entries.
+///
+/// This is created by [`tree`].
+#[derive(Debug)]
+pub struct TreePredicate {
+    entries: Vec<EntryPredicate>,
+    forbid_extra: bool,
+}
+
+impl TreePredicate {
+    /// Expect a file, relative to the directory, that satisfies `pred`.
+    ///
+    /// This accepts the same predicates as [`PathAssert::assert`], like a `&str` for the exact
+    /// content, a `Predicate<str>` like `predicate::str::contains` or a regex, or a `json::eq`
+    /// predicate.
+    ///
+    /// [`PathAssert::assert`]: crate::assert::PathAssert::assert
+    pub fn file<I, P>(mut self, path: impl Into<path::PathBuf>, pred: I) -> Self
+    where
+        I: IntoPathPredicate<P>,
+        P: predicates_core::Predicate<path::Path> + Send + Sync + 'static,
+    {
+        self.entries.push(EntryPredicate {
+            path: path.into(),
+            pred: pred.into_path().boxed(),
+        });
+        self
+    }
+
+    /// Expect a file or directory, relative to the directory, to exist.
+    ///
+    /// With [`TreePredicate::forbid_extra`], the content of a directory is allowed.
+    pub fn exists(self, path: impl Into<path::PathBuf>) -> Self {
+        self.file(path, predicates::path::exists())
+    }
+
+    /// Fail on files that aren't in the tree.
+    pub fn forbid_extra(mut self) -> Self {
+        self.forbid_extra = true;
+        self
+    }
+
+    fn extra(&self, path: &path::Path) -> Result<Vec<String>, String> {
+        if !self.forbid_extra {
+            return Ok(Vec::new());
+        }
+        let root = walk_root(path).map_err(|err| err.to_string())?;
+        let expected: Vec<_> = self.entries.iter().map(|e| relative(&e.path)).collect();
+        Ok(files(&root, &["**"])?
+            .into_iter()
+            .filter(|file| {
+                !expected.iter().any(|entry| {
+                    file == entry
+                        || file
+                            .strip_prefix(entry.as_str())
+                            .map(|rest| rest.starts_with('/'))
+                            .unwrap_or(false)
+                })
+            })
+            .collect())
+    }
+}
+
+impl reflection::PredicateReflection for TreePredicate {
+    fn parameters<'a>(&'a self) -> Box<dyn Iterator<Item = reflection::Parameter<'a>> + 'a> {
+        let params = self
+            .forbid_extra
+            .then(|| reflection::Parameter::new("forbid extra files", &self.forbid_extra));
+        Box::new(params.into_iter())
+    }
+
+    fn children<'a>(&'a self) -> Box<dyn Iterator<Item = reflection::Child<'a>> + 'a> {
+        let children = self
+            .entries
+            .iter()
+            .map(|entry| reflection::Child::new("entry", entry));
+        Box::new(children)
+    }
+}
+
+impl predicates_core::Predicate<path::Path> for TreePredicate {
+    fn eval(&self, variable: &path::Path) -> bool {
+        self.entries.iter().all(|entry| entry.eval(variable))
+            && matches!(self.extra(variable), Ok(extra) if extra.is_empty())
+    }
+
+    fn find_case<'a>(
+        &'a self,
+        expected: bool,
+        variable: &path::Path,
+    ) -> Option<reflection::Case<'a>> {
+        let extra = match self.extra(variable) {
+            Ok(extra) => extra,
+            Err(err) => return find_case(self, expected, variable, Err(err)),
+        };
+        let failures: Vec<_> = self
+            .entries
+            .iter()
+            .filter_map(|entry| entry.find_case(false, variable))
+            .collect();
+        let result = failures.is_empty() && extra.is_empty();
+        if result != expected {
+            return None;
+        }
+        let case = reflection::Case::new(Some(self), result);
+        if result {
+            return Some(case);
+        }
+        let case = failures
+            .into_iter()
+            .fold(case, |case, failure| case.add_child(failure));
+        let case = extra.into_iter().fold(case, |case, file| {
+            case.add_product(reflection::Product::new("unexpected", file))
+        });
+        Some(case.add_product(reflection::Product::new("contents", contents(variable))))
+    }
+}
+
+impl fmt::Display for TreePredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let palette = crate::Palette::color();
+        write!(
+            f,
+            "{:#} matches the expected tree of {:#} entries",
+            palette.key("var"),
+            palette.value(self.entries.len())
+        )
+    }
+}
+
+/// An entry of a [`TreePredicate`], checking the path relative to the directory.
+#[derive(Debug)]
+struct EntryPredicate {
+    path: path::PathBuf,
+    pred: BoxPredicate<path::Path>,
+}
+
+impl reflection::PredicateReflection for EntryPredicate {
+    fn children<'a>(&'a self) -> Box<dyn Iterator<Item = reflection::Child<'a>> + 'a> {
+        let params = vec![reflection::Child::new("predicate", &self.pred)];
+        Box::new(params.into_iter())
+    }
+}
+
+impl predicates_core::Predicate<path::Path> for EntryPredicate {
+    fn eval(&self, variable: &path::Path) -> bool {
+        self.pred.eval(&variable.join(&self.path))
+    }
+
+    fn find_case<'a>(
+        &'a self,
+        expected: bool,
+        variable: &path::Path,
+    ) -> Option<reflection::Case<'a>> {
+        let child = self.pred.find_case(expected, &variable.join(&self.path))?;
+        Some(reflection::Case::new(Some(self), expected).add_child(child))
+    }
+}
+
+impl fmt::Display for EntryPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let palette = crate::Palette::color();
+        write!(f, "{:#}", palette.key(self.path.display()))
+    }
+}
+
+/// Report `mismatches` as a [`reflection::Case`], with the directory's contents.
+fn find_case<'a>(
+    pred: &'a dyn reflection::PredicateReflection,
+    expected: bool,
+    path: &path::Path,
+    mismatches: Result<Vec<reflection::Product>, String>,
+) -> Option<reflection::Case<'a>> {
+    match mismatches {
+        Ok(mismatches) => {
+            let result = mismatches.is_empty();
+            (result == expected).then(|| {
+                mismatches
+                    .into_iter()
+                    .fold(reflection::Case::new(Some(pred), result), |case, m| {
+                        case.add_product(m)
+                    })
+                    .add_product(reflection::Product::new("contents", contents(path)))
+            })
+        }
+        Err(err) => super::error_case(pred, expected, err),
+    }
+}
+
+fn count_entries(path: &path::Path) -> Result<usize, String> {
+    fs::read_dir(path)
+        .map(|entries| entries.count())
+        .map_err(|err| err.to_string())
+}
+
+/// The files under `root` matching `patterns`, relative to `root` and with `/` separators.
+pub(crate) fn files<S>(root: &path::Path, patterns: &[S]) -> Result<Vec<String>, String>
+where
+    S: AsRef<str>,
+{
+    let mut files = Vec::new();
+    for entry in globwalk::GlobWalkerBuilder::from_patterns(root, patterns)
+        .follow_links(true)
+        .build()
+        .map_err(|err| err.to_string())?
+    {
+        let entry = entry.map_err(|err| err.to_string())?;
+        if entry.file_type().is_dir() {
+            continue;
+        }
+        let rel = entry
+            .path()
+            .strip_prefix(root)
+            .expect("entries to be under `root`");
+        files.push(relative(rel));
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// `path` with `/` separators and without `.` components, for comparing relative paths.
+pub(crate) fn relative(path: &path::Path) -> String {
+    let components: Vec<_> = path
+        .components()
+        .filter(|c| *c != path::Component::CurDir)
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect();
+    components.join("/")
+}
+
+/// The content of `root` as a tree, for a [`reflection::Product`].
+fn contents(root: &path::Path) -> String {
+    let mut out = String::from("\n.\n");
+    let mut shown = 0;
+    render_tree(root, "", &mut out, &mut shown);
+    out
+}
+
+fn render_tree(dir: &path::Path, prefix: &str, out: &mut String, shown: &mut usize) {
+    let mut entries: Vec<_> = match fs::read_dir(dir) {
+        Ok(entries) => entries.filter_map(Result::ok).collect(),
+        Err(err) => {
+            out.push_str(&format!("{prefix}└── (error: {err})\n"));
+            return;
+        }
+    };
+    entries.sort_by_key(|entry| entry.file_name());
+
+    let count = entries.len();
+    for (i, entry) in entries.into_iter().enumerate() {
+        if MAX_TREE_ENTRIES <= *shown {
+            out.push_str(&format!("{prefix}└── ... {} more\n", count - i));
+            return;
+        }
+        *shown += 1;
+
+        let (branch, indent) = if i + 1 == count {
+            ("└── ", "    ")
+        } else {
+            ("├── ", "│   ")
+        };
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let path = entry.path();
+        let file_type = entry.file_type().ok();
+        if file_type.map(|t| t.is_symlink()).unwrap_or(false) {
+            let target = fs::read_link(&path)
+                .map(|target| target.display().to_string())
+                .unwrap_or_default();
+            out.push_str(&format!("{prefix}{branch}{name} -> {target}\n"));
+        } else if file_type.map(|t| t.is_dir()).unwrap_or(false) {
+            out.push_str(&format!("{prefix}{branch}{name}/\n"));
+            render_tree(&path, &format!("{prefix}{indent}"), out, shown);
+        } else {
+            out.push_str(&format!("{prefix}{branch}{name}\n"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use predicates_core::Predicate;
+
+    use crate::fixture::FileWriteStr;
+    use crate::fixture::PathChild;
+    use crate::fixture::PathCreateDir;
+    use crate::fixture::TempDir;
+
+    fn fixture() -> TempDir {
+        let temp = TempDir::new().unwrap();
+        temp.child("a.txt").write_str("").unwrap();
+        temp.child("sub/b.rs").write_str("").unwrap();
+        temp.child("sub/deeper/c.rs").write_str("").unwrap();
+        temp.child("empty").create_dir_all().unwrap();
+        temp
+    }
+
+    #[test]
+    fn contents_tree() {
+        let temp = fixture();
+        assert_eq!(
+            contents(temp.path()),
+            "
+.
+├── a.txt
+├── empty/
+└── sub/
+    ├── b.rs
+    └── deeper/
+        └── c.rs
+"
+        );
+    }
+
+    #[test]
+    fn exact_contents() {
+        let temp = fixture();
+        let products = |globs: &[&str]| -> Vec<String> {
+            contains_exactly(globs.iter().copied())
+                .mismatches(temp.path())
+                .unwrap()
+                .iter()
+                .map(|p| format!("{}: {}", p.name(), p.value()))
+                .collect()
+        };
+
+        assert!(products(&["*.txt", "sub/**/*.rs"]).is_empty());
+        assert_eq!(
+            products(&["*.txt", "sub/*.rs", "*.md"]),
+            ["unmatched: *.md", "unexpected: sub/deeper/c.rs"]
+        );
+        assert_eq!(
+            products(&["**", "!**/*.rs"]),
+            ["unexpected: sub/b.rs", "unexpected: sub/deeper/c.rs"]
+        );
+    }
+
+    #[test]
+    fn tree_mismatches() {
+        let temp = fixture();
+        let expected = tree()
+            .file("a.txt", "hello")
+            .file("sub/b.rs", "")
+            .exists("sub/deeper")
+            .exists("missing.txt");
+        assert!(!expected.eval(temp.path()));
+        let case = expected.find_case(false, temp.path()).unwrap();
+        let failed: Vec<_> = case
+            .children()
+            .map(|c| c.predicate().unwrap().to_string())
+            .collect();
+        assert_eq!(failed.len(), 2, "{failed:?}");
+        assert!(failed[0].contains("a.txt"), "{failed:?}");
+        assert!(failed[1].contains("missing.txt"), "{failed:?}");
+
+        let expected = tree().file("a.txt", "").exists("sub").forbid_extra();
+        assert!(expected.eval(temp.path()));
+        let expected = tree().file("a.txt", "").exists("sub/b.rs").forbid_extra();
+        assert_eq!(expected.extra(temp.path()).unwrap(), ["sub/deeper/c.rs"]);
+        let expected = tree()
+            .file("./a.txt", "")
+            .exists("./sub/./b.rs")
+            .exists("sub/deeper")
+            .forbid_extra();
+        assert!(expected.extra(temp.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn not_a_directory() {
+        let temp = fixture();
+        let file = temp.path().join("a.txt");
+        assert!(!is_empty().eval(&file));
+        assert!(!contains(["a.txt"]).eval(&file));
+        assert!(!contains_exactly(["*"]).eval(&file));
+        assert!(is_empty().find_case(false, &file).is_some());
+    }
+}
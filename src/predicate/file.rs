@@ -0,0 +1,529 @@
+//! Streaming file content predicates.
+//!
+//! These compare content in chunks, stopping at the first difference, so files too large to read
+//! into memory can be compared.  On failure, only the bytes around the first difference are loaded
+//! for the diff.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use assert_fs::prelude::*;
+//! use assert_fs::predicate::file;
+//!
+//! let temp = assert_fs::TempDir::new().unwrap();
+//! let expected = temp.child("expected.log");
+//! expected.write_str("started\nfinished\n").unwrap();
+//! let actual = temp.child("actual.log");
+//! actual.write_str("started\nfinished\n").unwrap();
+//!
+//! actual.assert(file::eq_str("started\nfinished\n"));
+//! actual.assert(file::eq_file(expected.path()));
+//!
+//! temp.close().unwrap();
+//! ```
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::io::BufRead as _;
+use std::io::Read;
+use std::io::Seek;
+use std::path;
+
+use predicates_core::reflection;
+
+use super::bytes;
+use super::diff;
+
+/// Bytes compared at a time.
+const CHUNK_LEN: usize = 64 * 1024;
+/// Bytes loaded on either side of the first difference, for a text diff.
+const TEXT_WINDOW_LEN: usize = 1024;
+/// Lines kept on either side of the first difference, keeping the diff well under the 200 lines
+/// at which `difflib` starts treating common lines as junk.
+const TEXT_WINDOW_LINES: usize = 64;
+
+/// Creates a new predicate that compares a file's text to `expected`.
+///
+/// On failure, this reports the line of the first difference and a diff of the lines around it,
+/// falling back to a hexdump if the content isn't UTF-8.
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+/// use assert_fs::predicate::file;
+/// use predicates::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// let output = temp.child("output.txt");
+/// output.write_str("Hello\nWorld\n").unwrap();
+///
+/// output.assert(file::eq_str("Hello\nWorld\n"));
+/// output.assert(file::eq_str("Hello\nWorld").not());
+///
+/// temp.close().unwrap();
+/// ```
+pub fn eq_str(expected: impl Into<String>) -> StrEqPredicate {
+    StrEqPredicate {
+        expected: expected.into(),
+    }
+}
+
+/// Predicate that streams a file's content, comparing it to text.
+///
+/// This is created by [`eq_str`].
+#[derive(Debug, Clone)]
+pub struct StrEqPredicate {
+    expected: String,
+}
+
+impl reflection::PredicateReflection for StrEqPredicate {}
+
+impl predicates_core::Predicate<path::Path> for StrEqPredicate {
+    fn eval(&self, variable: &path::Path) -> bool {
+        is_eq(io::Cursor::new(self.expected.as_bytes()), variable)
+    }
+
+    fn find_case<'a>(
+        &'a self,
+        expected: bool,
+        variable: &path::Path,
+    ) -> Option<reflection::Case<'a>> {
+        find_case(
+            self,
+            expected,
+            io::Cursor::new(self.expected.as_bytes()),
+            variable,
+            Format::Text,
+        )
+    }
+}
+
+impl fmt::Display for StrEqPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let palette = crate::Palette::color();
+        write!(
+            f,
+            "{:#} {:#}",
+            palette.key("var =="),
+            palette.value(format!("{} bytes of text", self.expected.len()))
+        )
+    }
+}
+
+/// Creates a new predicate that compares a file's content to the content of `expected`.
+///
+/// On failure, this reports a diff of the lines around the first difference, falling back to a
+/// hexdump if the content isn't UTF-8.
+///
+/// [`PathAssert::assert`][crate::assert::PathAssert::assert] converts a `&Path` or `PathBuf` to
+/// this.
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+/// use assert_fs::predicate::file;
+/// use predicates::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// let expected = temp.child("expected.bin");
+/// expected.write_binary(&[0, 1, 2, 3]).unwrap();
+/// let output = temp.child("output.bin");
+/// output.write_binary(&[0, 1, 2, 3]).unwrap();
+///
+/// output.assert(file::eq_file(expected.path()));
+/// output.assert(file::eq_file(temp.child("missing.bin").path()).not());
+/// output.assert(expected.path());
+///
+/// temp.close().unwrap();
+/// ```
+pub fn eq_file(expected: impl Into<path::PathBuf>) -> FileEqPredicate {
+    FileEqPredicate {
+        expected: expected.into(),
+    }
+}
+
+/// Predicate that streams a file's content, comparing it to another file.
+///
+/// This is created by [`eq_file`].
+#[derive(Debug, Clone)]
+pub struct FileEqPredicate {
+    expected: path::PathBuf,
+}
+
+impl reflection::PredicateReflection for FileEqPredicate {}
+
+impl predicates_core::Predicate<path::Path> for FileEqPredicate {
+    fn eval(&self, variable: &path::Path) -> bool {
+        fs::File::open(&self.expected)
+            .map(|expected| is_eq(expected, variable))
+            .unwrap_or(false)
+    }
+
+    fn find_case<'a>(
+        &'a self,
+        expected: bool,
+        variable: &path::Path,
+    ) -> Option<reflection::Case<'a>> {
+        match fs::File::open(&self.expected) {
+            Ok(file) => find_case(self, expected, file, variable, Format::Text),
+            Err(err) => super::error_case(
+                self,
+                expected,
+                format!("failed to read {}: {err}", self.expected.display()),
+            ),
+        }
+    }
+}
+
+impl fmt::Display for FileEqPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let palette = crate::Palette::color();
+        write!(
+            f,
+            "{:#} {:#}",
+            palette.key("var =="),
+            palette.value(format!("content of {}", self.expected.display()))
+        )
+    }
+}
+
+/// How to show the content around a difference.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Format {
+    /// A hexdump.
+    Binary,
+    /// A diff of the lines, if they are UTF-8.
+    Text,
+}
+
+/// Where two streams first differ.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Difference {
+    pub(crate) offset: u64,
+    /// The 1-based line containing `offset`.
+    pub(crate) line: u64,
+}
+
+/// Whether `path`'s content is the same as `expected`.
+pub(crate) fn is_eq(mut expected: impl Read + Seek, path: &path::Path) -> bool {
+    let Ok(actual) = fs::File::open(path) else {
+        return false;
+    };
+    let lengths = stream_len(&mut expected).and_then(|e| Ok((e, actual.metadata()?.len())));
+    if matches!(lengths, Ok((e, a)) if e != a) || expected.rewind().is_err() {
+        return false;
+    }
+    matches!(first_difference(expected, actual), Ok(None))
+}
+
+/// Compare `path`'s content to `expected`, describing the first difference.
+pub(crate) fn find_case<'a>(
+    pred: &'a dyn reflection::PredicateReflection,
+    expected_result: bool,
+    mut expected: impl Read + Seek,
+    path: &path::Path,
+    format: Format,
+) -> Option<reflection::Case<'a>> {
+    let compared = fs::File::open(path).and_then(|mut actual| {
+        let difference = first_difference(&mut expected, &mut actual)?;
+        difference
+            .map(|difference| describe(&difference, format, &mut expected, &mut actual))
+            .transpose()
+    });
+    match compared {
+        Ok(None) => expected_result.then(|| reflection::Case::new(Some(pred), true)),
+        Ok(Some(products)) => (!expected_result).then(|| {
+            products
+                .into_iter()
+                .fold(reflection::Case::new(Some(pred), false), |case, product| {
+                    case.add_product(product)
+                })
+        }),
+        Err(err) => super::error_case(pred, expected_result, err),
+    }
+}
+
+/// Compare two streams in chunks, stopping at the first difference.
+///
+/// Content that continues after the other stream ends is a difference.
+pub(crate) fn first_difference(
+    expected: impl Read,
+    actual: impl Read,
+) -> io::Result<Option<Difference>> {
+    let mut expected = io::BufReader::with_capacity(CHUNK_LEN, expected);
+    let mut actual = io::BufReader::with_capacity(CHUNK_LEN, actual);
+    let mut offset = 0;
+    let mut line = 1;
+    loop {
+        let expected_chunk = expected.fill_buf()?;
+        let actual_chunk = actual.fill_buf()?;
+        let len = expected_chunk.len().min(actual_chunk.len());
+        if let Some(i) = bytes::first_difference(&expected_chunk[..len], &actual_chunk[..len]) {
+            line += count_lines(&expected_chunk[..i]);
+            return Ok(Some(Difference {
+                offset: offset + i as u64,
+                line,
+            }));
+        }
+        if len == 0 {
+            let ended = expected_chunk.is_empty() && actual_chunk.is_empty();
+            return Ok((!ended).then_some(Difference { offset, line }));
+        }
+        line += count_lines(&expected_chunk[..len]);
+        expected.consume(len);
+        actual.consume(len);
+        offset += len as u64;
+    }
+}
+
+/// Describe `difference` as products, loading only the bytes needed for the diff.
+fn describe(
+    difference: &Difference,
+    format: Format,
+    expected: &mut (impl Read + Seek),
+    actual: &mut (impl Read + Seek),
+) -> io::Result<Vec<reflection::Product>> {
+    let Difference { offset, line } = *difference;
+    let diff = match format {
+        Format::Text => text_diff(difference, expected, actual)?,
+        Format::Binary => None,
+    };
+    let (location, diff) = match diff {
+        Some(diff) => (format!("line {line} (offset {offset})"), ("diff", diff)),
+        None => (
+            format!("{offset:#x} ({offset})"),
+            ("hexdump", hexdump(offset, expected, actual)?),
+        ),
+    };
+    Ok(vec![
+        reflection::Product::new("first difference", location),
+        reflection::Product::new("expected length", stream_len(expected)?),
+        reflection::Product::new("actual length", stream_len(actual)?),
+        reflection::Product::new(diff.0, diff.1),
+    ])
+}
+
+/// A diff of the lines around `difference`, if they are UTF-8.
+fn text_diff(
+    difference: &Difference,
+    expected: &mut (impl Read + Seek),
+    actual: &mut (impl Read + Seek),
+) -> io::Result<Option<String>> {
+    let offset = difference.offset;
+    let mut split = usize::try_from(offset)
+        .map(|offset| offset.min(TEXT_WINDOW_LEN))
+        .unwrap_or(TEXT_WINDOW_LEN);
+    let start = offset - split as u64;
+    let mut expected = read_window(expected, start, 2 * TEXT_WINDOW_LEN)?;
+    let mut actual = read_window(actual, start, 2 * TEXT_WINDOW_LEN)?;
+
+    // Start and end on a line boundary, so the diff has whole lines, or at least on a character
+    // boundary when a line doesn't fit
+    let mut drained = 0;
+    if start != 0 {
+        drained = expected[..split]
+            .iter()
+            .position(|b| *b == b'\n')
+            .map(|newline| newline + 1)
+            .unwrap_or_else(|| char_start(&expected[..split]));
+        expected.drain(..drained);
+        actual.drain(..drained);
+        split -= drained;
+    }
+    for window in [&mut expected, &mut actual] {
+        if window.len() + drained == 2 * TEXT_WINDOW_LEN {
+            let end = window
+                .iter()
+                .rposition(|b| *b == b'\n')
+                .filter(|newline| split <= *newline)
+                .map(|newline| newline + 1)
+                .unwrap_or_else(|| char_end(window));
+            window.truncate(end);
+        }
+    }
+
+    let newlines = |window: &[u8]| {
+        window
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| **b == b'\n')
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>()
+    };
+    let before = newlines(&expected[..split]);
+    if TEXT_WINDOW_LINES < before.len() {
+        let skipped = before[before.len() - TEXT_WINDOW_LINES - 1] + 1;
+        expected.drain(..skipped);
+        actual.drain(..skipped);
+        split -= skipped;
+    }
+    for window in [&mut expected, &mut actual] {
+        if let Some(newline) = newlines(&window[split..]).get(TEXT_WINDOW_LINES) {
+            window.truncate(split + newline + 1);
+        }
+    }
+    let first_line = difference.line - count_lines(&expected[..split]);
+
+    // Differences in line endings don't show in a diff of the lines
+    match (std::str::from_utf8(&expected), std::str::from_utf8(&actual)) {
+        (Ok(expected), Ok(actual)) if !expected.lines().eq(actual.lines()) => {
+            Ok(Some(diff::unified_at(first_line, expected, actual)))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// A hexdump of the rows around `offset`.
+fn hexdump(
+    offset: u64,
+    expected: &mut (impl Read + Seek),
+    actual: &mut (impl Read + Seek),
+) -> io::Result<String> {
+    let start = bytes::hexdump_start(offset);
+    let expected_window = read_window(expected, start, bytes::HEXDUMP_LEN)?;
+    let actual_window = read_window(actual, start, bytes::HEXDUMP_LEN)?;
+    let mut dump = bytes::hexdump(start, &expected_window, &actual_window);
+    let end = start + bytes::HEXDUMP_LEN as u64;
+    let len = stream_len(expected)?.max(stream_len(actual)?);
+    if end < len {
+        dump.push_str(&format!("... {} more bytes not shown\n", len - end));
+    }
+    Ok(dump)
+}
+
+fn read_window(source: &mut (impl Read + Seek), start: u64, len: usize) -> io::Result<Vec<u8>> {
+    source.seek(io::SeekFrom::Start(start))?;
+    let mut window = Vec::with_capacity(len);
+    source.take(len as u64).read_to_end(&mut window)?;
+    Ok(window)
+}
+
+fn stream_len(source: &mut impl Seek) -> io::Result<u64> {
+    source.seek(io::SeekFrom::End(0))
+}
+
+/// Bytes to skip at the start of `window` to not begin in the middle of a UTF-8 character.
+fn char_start(window: &[u8]) -> usize {
+    window
+        .iter()
+        .take(3)
+        .take_while(|b| is_continuation(**b))
+        .count()
+}
+
+/// Where to end `window` to not cut a UTF-8 character off.
+fn char_end(window: &[u8]) -> usize {
+    let len = window.len();
+    let lead = (1..=len.min(4))
+        .map(|back| len - back)
+        .find(|i| !is_continuation(window[*i]));
+    match lead {
+        Some(lead) if len < lead + window[lead].leading_ones().max(1) as usize => lead,
+        _ => len,
+    }
+}
+
+fn is_continuation(byte: u8) -> bool {
+    byte & 0b1100_0000 == 0b1000_0000
+}
+
+fn count_lines(content: &[u8]) -> u64 {
+    content.iter().filter(|b| **b == b'\n').count() as u64
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn difference(expected: &[u8], actual: &[u8]) -> Option<Difference> {
+        first_difference(expected, actual).unwrap()
+    }
+
+    #[test]
+    fn first_differences() {
+        assert_eq!(difference(b"a\nb\n", b"a\nb\n"), None);
+        assert_eq!(
+            difference(b"a\nb\nc\n", b"a\nb\nd\n"),
+            Some(Difference { offset: 4, line: 3 })
+        );
+        assert_eq!(
+            difference(b"a\nb\n", b"a\n"),
+            Some(Difference { offset: 2, line: 2 })
+        );
+
+        let expected = vec![b'\n'; 3 * CHUNK_LEN];
+        let mut actual = expected.clone();
+        actual[2 * CHUNK_LEN + 1] = b'x';
+        assert_eq!(
+            difference(&expected, &actual),
+            Some(Difference {
+                offset: 2 * CHUNK_LEN as u64 + 1,
+                line: 2 * CHUNK_LEN as u64 + 2,
+            })
+        );
+    }
+
+    #[test]
+    fn text_diff_window() {
+        let expected: String = (0..1000).map(|i| format!("line {i}\n")).collect();
+        let actual = expected.replace("line 500\n", "line five hundred\n");
+        let offset = expected.find("line 500").unwrap() as u64;
+        let line = 501;
+
+        let diff = text_diff(
+            &Difference { offset, line },
+            &mut io::Cursor::new(expected.as_bytes()),
+            &mut io::Cursor::new(actual.as_bytes()),
+        )
+        .unwrap()
+        .unwrap();
+        assert!(diff.contains("@@ -501 +501 @@"), "{diff}");
+        assert!(diff.contains("line five hundred\n"), "{diff}");
+        assert!(!diff.contains("line 0\n"), "{diff}");
+    }
+
+    #[test]
+    fn text_diff_char_boundaries() {
+        let expected = "é".repeat(2000);
+        let mut actual = expected.clone();
+        actual.replace_range(2000..2002, "è");
+        assert_ne!(expected.as_bytes()[2001], actual.as_bytes()[2001]);
+
+        let diff = text_diff(
+            &Difference {
+                offset: 2001,
+                line: 1,
+            },
+            &mut io::Cursor::new(expected.as_bytes()),
+            &mut io::Cursor::new(actual.as_bytes()),
+        )
+        .unwrap()
+        .unwrap();
+        assert!(diff.contains("@@ -1 +1 @@"), "{diff}");
+        assert!(diff.contains("éèé"), "{diff}");
+    }
+
+    #[test]
+    fn binary_falls_back_to_hexdump() {
+        let products = describe(
+            &Difference { offset: 1, line: 1 },
+            Format::Text,
+            &mut io::Cursor::new(b"\xff\x00".as_slice()),
+            &mut io::Cursor::new(b"\xff\x01".as_slice()),
+        )
+        .unwrap();
+        let names: Vec<_> = products.iter().map(|p| p.name()).collect();
+        assert_eq!(
+            names,
+            [
+                "first difference",
+                "expected length",
+                "actual length",
+                "hexdump"
+            ]
+        );
+    }
+}
@@ -0,0 +1,478 @@
+//! JSON content predicates.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use assert_fs::prelude::*;
+//! use assert_fs::predicate::json;
+//!
+//! let temp = assert_fs::TempDir::new().unwrap();
+//! let output = temp.child("output.json");
+//! output.write_str(r#"{"version": 2, "tags": ["b", "a"]}"#).unwrap();
+//!
+//! output.assert(json::eq(r#"{"tags": ["a", "b"], "version": 2}"#).ignore_array_order());
+//! output.assert(json::json_at("/version", predicates::ord::eq(2)));
+//!
+//! temp.close().unwrap();
+//! ```
+
+use std::borrow::Cow;
+use std::fmt;
+use std::fs;
+use std::marker::PhantomData;
+use std::path;
+
+use predicates_core::reflection;
+
+use super::value;
+use super::value::CompareOptions;
+
+/// Creates a new predicate that parses a file as JSON and compares it to `expected`.
+///
+/// Object keys may be in any order and formatting is ignored.  Mismatches are reported as JSON
+/// pointers.
+///
+/// # Panics
+///
+/// If `expected` is not valid JSON.
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+/// use assert_fs::predicate::json;
+/// use predicates::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// let output = temp.child("output.json");
+/// output.write_str(r#"{"name": "foo", "time": 1700000000}"#).unwrap();
+///
+/// output.assert(json::eq(r#"{ "name": "foo" }"#).ignore_key("time"));
+/// output.assert(json::eq(r#"{ "name": "bar" }"#).ignore_key("time").not());
+///
+/// temp.close().unwrap();
+/// ```
+#[track_caller]
+pub fn eq(expected: &str) -> JsonEqPredicate {
+    let expected = serde_json::from_str(expected)
+        .unwrap_or_else(|err| panic!("expected value is not valid JSON: {err}"));
+    eq_value(expected)
+}
+
+/// Creates a new predicate that parses a file as JSON and compares it to `expected`.
+///
+/// See [`eq`].
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+/// use assert_fs::predicate::json;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// let output = temp.child("output.json");
+/// output.write_str(r#"{"name": "foo"}"#).unwrap();
+///
+/// output.assert(json::eq_value(serde_json::json!({ "name": "foo" })));
+///
+/// temp.close().unwrap();
+/// ```
+pub fn eq_value(expected: serde_json::Value) -> JsonEqPredicate {
+    JsonEqPredicate {
+        expected,
+        options: CompareOptions::default(),
+    }
+}
+
+/// Predicate that compares a JSON file to an expected value, ignoring formatting.
+///
+/// This is created by [`eq`] and [`eq_value`].
+#[derive(Debug, Clone)]
+pub struct JsonEqPredicate {
+    expected: serde_json::Value,
+    options: CompareOptions,
+}
+
+impl JsonEqPredicate {
+    /// Compare arrays as unordered collections.
+    ///
+    /// Each expected element is matched to the first unused actual element equal to it, so
+    /// pointers passed to [`ignore_pointer`][Self::ignore_pointer] refer to indices in the actual
+    /// document.  Matching is greedy, which can report a mismatch when ignoring a pointer makes an
+    /// element match one actual element but not another.
+    pub fn ignore_array_order(mut self) -> Self {
+        self.options.ignore_array_order = true;
+        self
+    }
+
+    /// Skip object members named `key`, wherever they are.
+    pub fn ignore_key(mut self, key: impl Into<String>) -> Self {
+        self.options.ignored_keys.push(key.into());
+        self
+    }
+
+    /// Skip the single value at the JSON pointer `pointer`, like `/build/timestamp`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use assert_fs::prelude::*;
+    /// use assert_fs::predicate::json;
+    /// use predicates::prelude::*;
+    ///
+    /// let temp = assert_fs::TempDir::new().unwrap();
+    /// let output = temp.child("output.json");
+    /// output
+    ///     .write_str(r#"{"build": {"time": 1700000000}, "time": 1}"#)
+    ///     .unwrap();
+    ///
+    /// output.assert(json::eq(r#"{"build": {}, "time": 1}"#).ignore_pointer("/build/time"));
+    /// output.assert(json::eq(r#"{"build": {}}"#).ignore_pointer("/build/time").not());
+    ///
+    /// temp.close().unwrap();
+    /// ```
+    pub fn ignore_pointer(mut self, pointer: impl Into<String>) -> Self {
+        self.options.ignored_pointers.push(pointer.into());
+        self
+    }
+}
+
+pub(crate) fn read_json(path: &path::Path) -> Result<serde_json::Value, String> {
+    let content = fs::read_to_string(path).map_err(|err| err.to_string())?;
+    serde_json::from_str(&content).map_err(|err| format!("invalid JSON: {err}"))
+}
+
+impl reflection::PredicateReflection for JsonEqPredicate {
+    fn parameters<'a>(&'a self) -> Box<dyn Iterator<Item = reflection::Parameter<'a>> + 'a> {
+        let mut params = Vec::new();
+        if self.options.ignore_array_order {
+            params.push(reflection::Parameter::new("ignore array order", &true));
+        }
+        for key in &self.options.ignored_keys {
+            params.push(reflection::Parameter::new("ignore key", key));
+        }
+        for pointer in &self.options.ignored_pointers {
+            params.push(reflection::Parameter::new("ignore pointer", pointer));
+        }
+        Box::new(params.into_iter())
+    }
+}
+
+impl predicates_core::Predicate<path::Path> for JsonEqPredicate {
+    fn eval(&self, variable: &path::Path) -> bool {
+        read_json(variable)
+            .map(|actual| value::compare(&self.expected, &actual, &self.options).is_empty())
+            .unwrap_or(false)
+    }
+
+    fn find_case<'a>(
+        &'a self,
+        expected: bool,
+        variable: &path::Path,
+    ) -> Option<reflection::Case<'a>> {
+        value::find_case(
+            self,
+            expected,
+            &self.expected,
+            read_json(variable),
+            &self.options,
+            value::Mismatch::pointer,
+        )
+    }
+}
+
+impl fmt::Display for JsonEqPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let palette = crate::Palette::color();
+        write!(
+            f,
+            "{:#} {:#} {:#}",
+            palette.key("var"),
+            palette.key("=="),
+            palette.value(self.expected.to_string())
+        )
+    }
+}
+
+/// Creates a new predicate that parses a file as JSON and applies `pred` to the value at
+/// `pointer`.
+///
+/// `pred` may be any predicate on `str`, a number, or `bool`, see [`JsonScalar`].  Invalid JSON,
+/// a missing `pointer`, or a value of another type fail the predicate.
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+/// use assert_fs::predicate::json::json_at;
+/// use predicates::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// let output = temp.child("output.json");
+/// output
+///     .write_str(r#"{"build": {"target": "x86_64", "jobs": 4}}"#)
+///     .unwrap();
+///
+/// output.assert(json_at("/build/target", predicate::eq("x86_64")));
+/// output.assert(json_at("/build/target", predicate::str::starts_with("x86")));
+/// output.assert(json_at("/build/jobs", predicate::gt(1)));
+/// output.assert(json_at("/build/profile", predicate::eq("release")).not());
+///
+/// temp.close().unwrap();
+/// ```
+pub fn json_at<P, T>(pointer: impl Into<String>, pred: P) -> JsonAtPredicate<P, T>
+where
+    P: JsonScalarPredicate<T>,
+    T: JsonScalar + ?Sized,
+{
+    JsonAtPredicate {
+        pointer: pointer.into(),
+        pred,
+        _type: PhantomData,
+    }
+}
+
+/// Types of JSON values that [`json_at`] can apply a predicate to.
+pub trait JsonScalar: ToOwned {
+    /// Extract `Self` from `value`, if it has a compatible type.
+    fn from_json(value: &serde_json::Value) -> Option<Cow<'_, Self>>;
+}
+
+/// Predicates that [`json_at`] can apply to a JSON value of type `T`.
+///
+/// This is implemented for predicates on each [`JsonScalar`], so `T` is inferred from the
+/// predicate, like `str` for `predicate::eq("x86_64")`.
+pub trait JsonScalarPredicate<T>: predicates_core::Predicate<T>
+where
+    T: JsonScalar + ?Sized,
+{
+}
+
+impl JsonScalar for str {
+    fn from_json(value: &serde_json::Value) -> Option<Cow<'_, Self>> {
+        value.as_str().map(Cow::Borrowed)
+    }
+}
+
+impl JsonScalar for bool {
+    fn from_json(value: &serde_json::Value) -> Option<Cow<'_, Self>> {
+        value.as_bool().map(Cow::Owned)
+    }
+}
+
+impl JsonScalar for f64 {
+    fn from_json(value: &serde_json::Value) -> Option<Cow<'_, Self>> {
+        value.as_f64().map(Cow::Owned)
+    }
+}
+
+macro_rules! impl_integer_json_scalar {
+    ($($ty:ty),*) => {
+        $(
+            impl JsonScalar for $ty {
+                fn from_json(value: &serde_json::Value) -> Option<Cow<'_, Self>> {
+                    let value = match value.as_i64() {
+                        Some(n) => Self::try_from(n).ok(),
+                        None => value.as_u64().and_then(|n| Self::try_from(n).ok()),
+                    };
+                    value.map(Cow::Owned)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_json_scalar!(i32, i64, u32, u64, usize);
+
+// One impl per type, rather than for every `JsonScalar`, lets the compiler pick `T` by which
+// `Predicate<T>` the predicate implements.
+macro_rules! impl_json_scalar_predicate {
+    ($($ty:ty),*) => {
+        $(
+            impl<P> JsonScalarPredicate<$ty> for P where P: predicates_core::Predicate<$ty> {}
+        )*
+    };
+}
+
+impl_json_scalar_predicate!(str, bool, f64, i32, i64, u32, u64, usize);
+
+/// Predicate that applies a predicate to a value within a JSON file.
+///
+/// This is created by [`json_at`].
+pub struct JsonAtPredicate<P, T>
+where
+    T: ?Sized,
+{
+    pointer: String,
+    pred: P,
+    _type: PhantomData<fn(&T)>,
+}
+
+impl<P, T> JsonAtPredicate<P, T>
+where
+    P: JsonScalarPredicate<T>,
+    T: JsonScalar + ?Sized,
+{
+    fn lookup(&self, path: &path::Path) -> Result<serde_json::Value, String> {
+        let document = read_json(path)?;
+        document
+            .pointer(&self.pointer)
+            .cloned()
+            .ok_or_else(|| format!("no value at `{}`", self.pointer))
+    }
+}
+
+impl<P, T> fmt::Debug for JsonAtPredicate<P, T>
+where
+    P: fmt::Debug,
+    T: ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JsonAtPredicate")
+            .field("pointer", &self.pointer)
+            .field("pred", &self.pred)
+            .finish()
+    }
+}
+
+impl<P, T> reflection::PredicateReflection for JsonAtPredicate<P, T>
+where
+    P: JsonScalarPredicate<T>,
+    T: JsonScalar + ?Sized,
+{
+    fn children<'a>(&'a self) -> Box<dyn Iterator<Item = reflection::Child<'a>> + 'a> {
+        let params = vec![reflection::Child::new("predicate", &self.pred)];
+        Box::new(params.into_iter())
+    }
+}
+
+impl<P, T> predicates_core::Predicate<path::Path> for JsonAtPredicate<P, T>
+where
+    P: JsonScalarPredicate<T>,
+    T: JsonScalar + ?Sized,
+{
+    fn eval(&self, variable: &path::Path) -> bool {
+        self.lookup(variable)
+            .map(|value| {
+                T::from_json(&value)
+                    .map(|value| self.pred.eval(&value))
+                    .unwrap_or(false)
+            })
+            .unwrap_or(false)
+    }
+
+    fn find_case<'a>(
+        &'a self,
+        expected: bool,
+        variable: &path::Path,
+    ) -> Option<reflection::Case<'a>> {
+        let value = match self.lookup(variable) {
+            Ok(value) => value,
+            Err(err) => return super::error_case(self, expected, err),
+        };
+        let Some(scalar) = T::from_json(&value) else {
+            return super::error_case(
+                self,
+                expected,
+                format!("expected {}, found `{value}`", std::any::type_name::<T>()),
+            );
+        };
+        let child = self.pred.find_case(expected, &scalar)?;
+        Some(
+            reflection::Case::new(Some(self), expected)
+                .add_product(reflection::Product::new("value", value.to_string()))
+                .add_child(child),
+        )
+    }
+}
+
+impl<P, T> fmt::Display for JsonAtPredicate<P, T>
+where
+    P: JsonScalarPredicate<T>,
+    T: JsonScalar + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let palette = crate::Palette::color();
+        write!(
+            f,
+            "{:#} {:#}",
+            palette.key("value at"),
+            palette.value(&self.pointer)
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use predicates_core::Predicate;
+    use predicates_tree::CaseTreeExt;
+
+    #[test]
+    fn reports_pointers() {
+        let temp = crate::TempDir::new().unwrap();
+        let path = temp.path().join("output.json");
+        fs::write(&path, r#"{"a": {"b/c": 1}, "d": [1, 2]}"#).unwrap();
+
+        let pred = eq(r#"{"a": {"b/c": 2}, "d": [1, 2, 3]}"#);
+        let case = pred.find_case(false, &path).unwrap();
+        let output = case.tree().to_string();
+        assert!(
+            output.contains("/a/b~1c: expected `2`, found `1`"),
+            "{output}"
+        );
+        assert!(output.contains("/d/2: missing, expected `3`"), "{output}");
+    }
+
+    #[test]
+    fn ignore_key_is_literal() {
+        let temp = crate::TempDir::new().unwrap();
+        let path = temp.path().join("output.json");
+        fs::write(&path, r#"{"/a": 1, "a": 2}"#).unwrap();
+
+        assert!(eq(r#"{"a": 2}"#).ignore_key("/a").eval(&path));
+        assert!(!eq(r#"{"/a": 1}"#).ignore_key("/a").eval(&path));
+        assert!(eq(r#"{"/a": 1}"#).ignore_pointer("/a").eval(&path));
+    }
+
+    #[test]
+    fn invalid_json() {
+        let temp = crate::TempDir::new().unwrap();
+        let path = temp.path().join("output.json");
+        fs::write(&path, "{\n  \"a\": \n}").unwrap();
+
+        let pred = eq("{}");
+        assert!(!pred.eval(&path));
+        let case = pred.find_case(false, &path).unwrap();
+        let output = case.tree().to_string();
+        assert!(output.contains("invalid JSON"), "{output}");
+        assert!(output.contains("line 3 column 1"), "{output}");
+    }
+
+    #[test]
+    fn json_at_errors() {
+        use predicates::prelude::*;
+
+        fn failure<P, T>(pred: JsonAtPredicate<P, T>, path: &path::Path) -> String
+        where
+            P: JsonScalarPredicate<T>,
+            T: JsonScalar + ?Sized,
+        {
+            pred.find_case(false, path).unwrap().tree().to_string()
+        }
+
+        let temp = crate::TempDir::new().unwrap();
+        let path = temp.path().join("output.json");
+        fs::write(&path, r#"{"build": {"target": "x86_64", "jobs": 4}}"#).unwrap();
+
+        let output = failure(json_at("/build/target", predicate::eq("arm")), &path);
+        assert!(output.contains("value: \"x86_64\""), "{output}");
+
+        let output = failure(json_at("/build/profile", predicate::str::diff("a")), &path);
+        assert!(output.contains("no value at `/build/profile`"), "{output}");
+
+        let output = failure(json_at("/build/jobs", predicate::eq("4")), &path);
+        assert!(output.contains("expected str, found `4`"), "{output}");
+    }
+}
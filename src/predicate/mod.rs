@@ -0,0 +1,39 @@
+//! Predicates for validating files with [`PathAssert`].
+//!
+//! These complement [`predicates`], understanding the content of files.
+//!
+//! [`PathAssert`]: crate::assert::PathAssert
+
+pub mod bytes;
+pub mod dir;
+pub mod file;
+#[cfg(feature = "serde")]
+pub mod json;
+pub mod str;
+#[cfg(feature = "serde")]
+pub mod toml;
+#[cfg(feature = "yaml")]
+pub mod yaml;
+
+pub(crate) mod diff;
+#[cfg(feature = "serde")]
+mod value;
+
+#[cfg(feature = "serde")]
+pub use value::DocumentEqPredicate;
+
+use std::fmt;
+
+use predicates_core::reflection;
+
+/// Report that `pred` couldn't be evaluated, like when the file can't be read, as a failing
+/// [`reflection::Case`].
+pub(crate) fn error_case<'a>(
+    pred: &'a dyn reflection::PredicateReflection,
+    expected: bool,
+    err: impl fmt::Display + 'static,
+) -> Option<reflection::Case<'a>> {
+    (!expected).then(|| {
+        reflection::Case::new(Some(pred), false).add_product(reflection::Product::new("error", err))
+    })
+}
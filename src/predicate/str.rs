@@ -0,0 +1,373 @@
+//! String content predicates.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use assert_fs::prelude::*;
+//! use assert_fs::predicate::str::wildcard_eq;
+//!
+//! let temp = assert_fs::TempDir::new().unwrap();
+//! let log = temp.child("build.log");
+//! log.write_str(&format!(
+//!     "Building {}\nstep 1\nstep 2\nFinished in 0.42s\n",
+//!     temp.path().display()
+//! ))
+//! .unwrap();
+//!
+//! log.assert(
+//!     wildcard_eq("Building [ROOT]\n...\nFinished in [..]s\n").redact("[ROOT]", temp.path()),
+//! );
+//!
+//! temp.close().unwrap();
+//! ```
+
+use std::collections::HashSet;
+use std::fmt;
+use std::path;
+
+use predicates_core::reflection;
+
+use super::diff;
+
+/// Creates a new predicate that compares text to `expected`, with placeholders for content that
+/// varies.
+///
+/// `expected` may contain:
+/// - `[..]` to match any characters within a line
+/// - A `...` line to match any number of lines
+/// - Named placeholders, like `[ROOT]` or `[HASH]`, for values registered with
+///   [`WildcardPredicate::redact`]
+///
+/// On failure, the diff shows the actual text with the redactions applied.
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+/// use assert_fs::predicate::str::wildcard_eq;
+/// use predicates::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// let output = temp.child("output.txt");
+/// output.write_str("id: 3f2a\ncreated: 2024-01-01\n").unwrap();
+///
+/// output.assert(wildcard_eq("id: [HASH]\ncreated: [..]\n").redact("[HASH]", "3f2a"));
+/// output.assert(wildcard_eq("id: [..]\n").not());
+///
+/// temp.close().unwrap();
+/// ```
+pub fn wildcard_eq(expected: impl Into<String>) -> WildcardPredicate {
+    WildcardPredicate {
+        expected: expected.into(),
+        redactions: Vec::new(),
+    }
+}
+
+/// Predicate that compares text to an expected pattern with wildcards and redactions.
+///
+/// This is created by [`wildcard_eq`].
+#[derive(Debug, Clone)]
+pub struct WildcardPredicate {
+    expected: String,
+    redactions: Vec<(String, String)>,
+}
+
+impl WildcardPredicate {
+    /// Replace `value` with `placeholder` in the actual text before comparing.
+    ///
+    /// `placeholder` must be an upper-case name in brackets, like `[ROOT]`.  Longer values are
+    /// replaced first, so a placeholder for a directory and one for a file within it can be
+    /// combined.  Paths are redacted as displayed by [`Path::display`][path::Path::display].
+    ///
+    /// # Panics
+    ///
+    /// If `placeholder` is not a valid name or `value` is empty.
+    #[track_caller]
+    pub fn redact(mut self, placeholder: &str, value: impl RedactedValue) -> Self {
+        let valid = placeholder
+            .strip_prefix('[')
+            .and_then(|name| name.strip_suffix(']'))
+            .map(|name| {
+                !name.is_empty() && name.chars().all(|c| c.is_ascii_uppercase() || c == '_')
+            })
+            .unwrap_or(false);
+        assert!(
+            valid,
+            "invalid placeholder `{placeholder}`, expected `[NAME]`"
+        );
+        let value = value.into_redacted();
+        assert!(!value.is_empty(), "empty value for `{placeholder}`");
+        self.redactions.push((placeholder.to_owned(), value));
+        self.redactions
+            .sort_by(|(_, a), (_, b)| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        self
+    }
+
+    pub(crate) fn redacted(&self, actual: &str) -> String {
+        let mut actual = actual.to_owned();
+        for (placeholder, value) in &self.redactions {
+            actual = actual.replace(value.as_str(), placeholder);
+        }
+        actual
+    }
+}
+
+/// Values that can be redacted with [`WildcardPredicate::redact`].
+pub trait RedactedValue {
+    /// The text to replace.
+    fn into_redacted(self) -> String;
+}
+
+impl RedactedValue for &str {
+    fn into_redacted(self) -> String {
+        self.to_owned()
+    }
+}
+
+impl RedactedValue for String {
+    fn into_redacted(self) -> String {
+        self
+    }
+}
+
+impl RedactedValue for &path::Path {
+    fn into_redacted(self) -> String {
+        self.display().to_string()
+    }
+}
+
+impl RedactedValue for path::PathBuf {
+    fn into_redacted(self) -> String {
+        self.display().to_string()
+    }
+}
+
+/// Rewrite `actual` to look like `expected` wherever the wildcards match.
+///
+/// When everything matches, the result is equal to `expected`.  Otherwise, it is suitable for
+/// diffing against `expected`, only showing the lines that didn't match.
+pub(crate) fn normalize(expected: &str, actual: &str) -> String {
+    let expected: Vec<_> = expected.split_inclusive('\n').collect();
+    let actual: Vec<_> = actual.split_inclusive('\n').collect();
+    let mut matcher = Matcher {
+        expected: &expected,
+        actual: &actual,
+        failed: HashSet::new(),
+    };
+
+    let mut normalized = String::new();
+    let mut a = 0;
+    let mut e = 0;
+    while let Some(pattern) = expected.get(e) {
+        if is_elided(pattern) {
+            match expected.get(e + 1) {
+                None => {
+                    normalized.push_str(pattern);
+                    a = actual.len();
+                }
+                Some(next) => {
+                    // Prefer where the rest matches, otherwise where the next line does so the
+                    // diff still lines up with it
+                    let Some(offset) = (a..=actual.len())
+                        .position(|start| matcher.matches(e + 1, start))
+                        .or_else(|| actual[a..].iter().position(|line| line_matches(next, line)))
+                    else {
+                        break;
+                    };
+                    normalized.push_str(pattern);
+                    a += offset;
+                }
+            }
+            e += 1;
+        } else if let Some(line) = actual.get(a).filter(|line| line_matches(pattern, line)) {
+            normalized.push_str(content(pattern));
+            normalized.push_str(&line[content(line).len()..]);
+            a += 1;
+            e += 1;
+        } else {
+            break;
+        }
+    }
+    for line in &actual[a..] {
+        normalized.push_str(line);
+    }
+    normalized
+}
+
+/// Matches lines of `expected` to lines of `actual`, trying every number of lines for each `...`.
+struct Matcher<'a> {
+    expected: &'a [&'a str],
+    actual: &'a [&'a str],
+    /// Positions already known not to match, bounding the work to one attempt per position.
+    failed: HashSet<(usize, usize)>,
+}
+
+impl Matcher<'_> {
+    /// Whether `expected[e..]` matches `actual[a..]`.
+    fn matches(&mut self, mut e: usize, mut a: usize) -> bool {
+        let start = (e, a);
+        if self.failed.contains(&start) {
+            return false;
+        }
+        // Lines up to the next `...` match one-to-one
+        let mut matches = true;
+        while let Some(pattern) = self.expected.get(e).filter(|line| !is_elided(line)) {
+            if !self
+                .actual
+                .get(a)
+                .is_some_and(|line| line_matches(pattern, line))
+            {
+                matches = false;
+                break;
+            }
+            e += 1;
+            a += 1;
+        }
+        let matches = matches
+            && if e == self.expected.len() {
+                a == self.actual.len()
+            } else {
+                e + 1 == self.expected.len()
+                    || (a..=self.actual.len()).any(|next| self.matches(e + 1, next))
+            };
+        if !matches {
+            self.failed.insert(start);
+        }
+        matches
+    }
+}
+
+fn content(line: &str) -> &str {
+    line.trim_end_matches(['\n', '\r'])
+}
+
+fn is_elided(line: &str) -> bool {
+    content(line) == "..."
+}
+
+fn line_matches(pattern: &str, line: &str) -> bool {
+    let pattern = content(pattern);
+    let line = content(line);
+    let mut parts = pattern.split("[..]");
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = line.strip_prefix(first) else {
+        return false;
+    };
+    let mut parts: Vec<_> = parts.collect();
+    let Some(last) = parts.pop() else {
+        return rest.is_empty();
+    };
+    for part in parts {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+impl reflection::PredicateReflection for WildcardPredicate {
+    fn parameters<'a>(&'a self) -> Box<dyn Iterator<Item = reflection::Parameter<'a>> + 'a> {
+        let params: Vec<_> = self
+            .redactions
+            .iter()
+            .map(|(placeholder, value)| reflection::Parameter::new(placeholder, value))
+            .collect();
+        Box::new(params.into_iter())
+    }
+}
+
+impl predicates_core::Predicate<str> for WildcardPredicate {
+    fn eval(&self, variable: &str) -> bool {
+        normalize(&self.expected, &self.redacted(variable)) == self.expected
+    }
+
+    fn find_case<'a>(&'a self, expected: bool, variable: &str) -> Option<reflection::Case<'a>> {
+        let normalized = normalize(&self.expected, &self.redacted(variable));
+        let result = normalized == self.expected;
+        if result != expected {
+            return None;
+        }
+        let mut case = reflection::Case::new(Some(self), result);
+        if !result {
+            case = case.add_product(reflection::Product::new(
+                "diff",
+                diff::unified(&self.expected, &normalized),
+            ));
+        }
+        Some(case)
+    }
+}
+
+impl fmt::Display for WildcardPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let palette = crate::Palette::color();
+        write!(
+            f,
+            "{:#} {:#}",
+            palette.key("var matches"),
+            palette.value("expected")
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use predicates_core::Predicate;
+
+    #[test]
+    fn wildcards() {
+        let matches = |expected: &str, actual: &str| wildcard_eq(expected).eval(actual);
+
+        assert!(matches("a [..] c\n", "a b c\n"));
+        assert!(matches("a [..] c\n", "a  c\n"));
+        assert!(!matches("a [..] c\n", "a b c d\n"));
+        assert!(matches("[..]b[..]b[..]\n", "abcbd\n"));
+        assert!(!matches("ab[..]ba\n", "aba\n"));
+        assert!(!matches("a\n", "a"));
+        assert!(matches("a\r\n", "a\r\n"));
+
+        assert!(matches("a\n...\nd\n", "a\nb\nc\nd\n"));
+        assert!(matches("a\n...\nd\n", "a\nd\n"));
+        assert!(matches("a\n...\n", "a\nb\nc\n"));
+        assert!(matches("...\nc [..]\n", "a\nc x\n"));
+        assert!(!matches("a\n...\nd\n", "a\nb\nc\n"));
+        assert!(matches("a\n...\n}\nend\n", "a\n}\nb\n}\nend\n"));
+        assert!(matches("...\nx\n...\nx\n", "x\nx\nx\n"));
+        assert!(!matches("a\n...\n}\nend\n", "a\n}\nb\n}\n"));
+    }
+
+    #[test]
+    fn redactions() {
+        let pred = wildcard_eq("[ROOT]/[FILE] in [ROOT]\n")
+            .redact("[ROOT]", "/tmp/xyz")
+            .redact("[FILE]", "foo.txt");
+        assert!(pred.eval("/tmp/xyz/foo.txt in /tmp/xyz\n"));
+
+        let pred = wildcard_eq("[ROOT]\n[SUB]\n")
+            .redact("[ROOT]", "/tmp/xyz")
+            .redact("[SUB]", "/tmp/xyz/sub");
+        assert!(pred.eval("/tmp/xyz\n/tmp/xyz/sub\n"));
+    }
+
+    #[test]
+    fn normalize_hides_matched_wildcards() {
+        assert_eq!(
+            normalize("a [..]\n...\nd\ne\n", "a b\nb\nc\nd\nf\n"),
+            "a [..]\n...\nd\nf\n"
+        );
+        assert_eq!(normalize("a\n...\nd\n", "b\nc\n"), "b\nc\n");
+        assert_eq!(
+            normalize("a\n...\n}\nend\n", "a\n}\nb\n}\nstart\n"),
+            "a\n...\n}\nb\n}\nstart\n"
+        );
+    }
+
+    #[test]
+    #[should_panic = "invalid placeholder"]
+    fn invalid_placeholder() {
+        let _ = wildcard_eq("").redact("ROOT", "/tmp");
+    }
+}
@@ -0,0 +1,132 @@
+//! TOML content predicates.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use assert_fs::prelude::*;
+//! use assert_fs::predicate::toml;
+//!
+//! let temp = assert_fs::TempDir::new().unwrap();
+//! let manifest = temp.child("Cargo.toml");
+//! manifest
+//!     .write_str("[package]\nname = \"foo\" # the name\nversion = \"0.1.0\"\n")
+//!     .unwrap();
+//!
+//! manifest.assert(toml::eq(r#"package = { version = "0.1.0", name = "foo" }"#));
+//!
+//! temp.close().unwrap();
+//! ```
+
+use serde_json::Value;
+
+use super::value;
+use super::value::DocumentEqPredicate;
+use super::value::Format;
+
+static FORMAT: Format = Format {
+    name: "TOML",
+    parse: |content| parse(content).map_err(|err| err.to_string()),
+};
+
+/// Creates a new predicate that parses a file as TOML and compares it to `expected`.
+///
+/// Formatting, comments, key order, and how tables are written are ignored.  Mismatches are
+/// reported by their key path, like `dependencies.foo.version`.
+///
+/// # Panics
+///
+/// If `expected` is not valid TOML.
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+/// use assert_fs::predicate::toml;
+/// use predicates::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// let manifest = temp.child("Cargo.toml");
+/// manifest.write_str("[dependencies]\nfoo = \"1.0\"\n").unwrap();
+///
+/// manifest.assert(toml::eq("dependencies.foo = \"1.0\""));
+/// manifest.assert(toml::eq("dependencies.foo = \"2.0\"").not());
+///
+/// temp.close().unwrap();
+/// ```
+#[track_caller]
+pub fn eq(expected: &str) -> TomlEqPredicate {
+    DocumentEqPredicate::new(&FORMAT, expected)
+}
+
+/// Predicate that compares a TOML file to an expected document, ignoring formatting.
+///
+/// This is created by [`eq`].
+pub type TomlEqPredicate = DocumentEqPredicate;
+
+fn parse(content: &str) -> Result<Value, ::toml::de::Error> {
+    let table = ::toml::from_str::<::toml::Table>(content)?;
+    Ok(convert(::toml::Value::Table(table)))
+}
+
+fn convert(value: ::toml::Value) -> Value {
+    match value {
+        ::toml::Value::String(value) => Value::String(value),
+        ::toml::Value::Integer(value) => Value::from(value),
+        ::toml::Value::Float(value) => value::float(value),
+        ::toml::Value::Boolean(value) => Value::Bool(value),
+        ::toml::Value::Datetime(value) => Value::String(value.to_string()),
+        ::toml::Value::Array(values) => Value::Array(values.into_iter().map(convert).collect()),
+        ::toml::Value::Table(table) => Value::Object(
+            table
+                .into_iter()
+                .map(|(key, value)| (key, convert(value)))
+                .collect(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::fs;
+
+    use predicates_core::Predicate;
+    use predicates_tree::CaseTreeExt;
+
+    #[test]
+    fn reports_key_paths() {
+        let temp = crate::TempDir::new().unwrap();
+        let path = temp.path().join("Cargo.toml");
+        fs::write(
+            &path,
+            "[package]\nname = \"foo\"\nauthors = [\"a\"]\n\n[dependencies]\nbar = \"1.0\"\n",
+        )
+        .unwrap();
+
+        let pred = eq(concat!(
+            "package = { name = \"foo\", authors = [\"b\"] }\n",
+            "dependencies = { bar = \"1.0\", baz = \"2\" }",
+        ));
+        let output = pred.find_case(false, &path).unwrap().tree().to_string();
+        assert!(
+            output.contains("package.authors[0]: expected `\"b\"`, found `\"a\"`"),
+            "{output}"
+        );
+        assert!(
+            output.contains("dependencies.baz: missing, expected `\"2\"`"),
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn non_finite_floats() {
+        let temp = crate::TempDir::new().unwrap();
+        let path = temp.path().join("Cargo.toml");
+        fs::write(&path, "x = nan\ny = -inf\n").unwrap();
+
+        assert!(eq("x = nan\ny = -inf").eval(&path));
+        assert!(!eq("x = \"nan\"\ny = -inf").eval(&path));
+        assert!(!eq("x = nan\ny = inf").eval(&path));
+    }
+}
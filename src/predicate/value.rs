@@ -0,0 +1,461 @@
+//! Structural comparison of parsed documents.
+
+use std::fmt;
+use std::fs;
+use std::path;
+
+use predicates_core::reflection;
+use serde_json::Value;
+
+/// How [`compare`] treats differences that don't matter to the caller.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct CompareOptions {
+    pub(crate) ignore_array_order: bool,
+    /// Object members with these names are skipped at any depth.
+    pub(crate) ignored_keys: Vec<String>,
+    /// JSON pointers of values that are skipped.
+    pub(crate) ignored_pointers: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+/// A difference between the expected and actual documents.
+#[derive(Clone, Debug)]
+pub(crate) struct Mismatch {
+    path: Vec<Segment>,
+    kind: MismatchKind,
+}
+
+#[derive(Clone, Debug)]
+enum MismatchKind {
+    Missing(Value),
+    Unexpected(Value),
+    Different { expected: Value, actual: Value },
+}
+
+impl Mismatch {
+    /// The location as a JSON pointer, like `/dependencies/0/name`.
+    pub(crate) fn pointer(&self) -> String {
+        pointer(&self.path)
+    }
+
+    /// The location as a key path, like `dependencies[0].name`.
+    pub(crate) fn key_path(&self) -> String {
+        let mut out = String::new();
+        for segment in &self.path {
+            match segment {
+                Segment::Key(key) => {
+                    if !out.is_empty() {
+                        out.push('.');
+                    }
+                    if is_bare_key(key) {
+                        out.push_str(key);
+                    } else {
+                        out.push_str(&Value::String(key.clone()).to_string());
+                    }
+                }
+                Segment::Index(index) => {
+                    out.push_str(&format!("[{index}]"));
+                }
+            }
+        }
+        out
+    }
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            MismatchKind::Missing(expected) => write!(f, "missing, expected `{expected}`"),
+            MismatchKind::Unexpected(actual) => write!(f, "unexpected `{actual}`"),
+            MismatchKind::Different { expected, actual } => {
+                write!(f, "expected `{expected}`, found `{actual}`")
+            }
+        }
+    }
+}
+
+/// Compare `actual` to `expected`, returning every difference.
+pub(crate) fn compare(expected: &Value, actual: &Value, options: &CompareOptions) -> Vec<Mismatch> {
+    let mut mismatches = Vec::new();
+    let mut path = Vec::new();
+    compare_at(expected, actual, options, &mut path, &mut mismatches);
+    mismatches
+}
+
+/// Report the result of comparing `actual` to `expected` as a [`reflection::Case`].
+///
+/// Each mismatch is a product keyed by its `location`.
+pub(crate) fn find_case<'a>(
+    pred: &'a dyn reflection::PredicateReflection,
+    expected_result: bool,
+    expected: &Value,
+    actual: Result<Value, String>,
+    options: &CompareOptions,
+    location: fn(&Mismatch) -> String,
+) -> Option<reflection::Case<'a>> {
+    let actual = match actual {
+        Ok(actual) => actual,
+        Err(err) => return super::error_case(pred, expected_result, err),
+    };
+    let mismatches = compare(expected, &actual, options);
+    let result = mismatches.is_empty();
+    (result == expected_result).then(|| {
+        mismatches
+            .into_iter()
+            .fold(reflection::Case::new(Some(pred), result), |case, m| {
+                let location = location(&m);
+                let location = if location.is_empty() {
+                    "(root)".to_owned()
+                } else {
+                    location
+                };
+                case.add_product(reflection::Product::new(location, m))
+            })
+    })
+}
+
+/// A document format that can be compared by [`DocumentEqPredicate`].
+#[derive(Debug)]
+pub(crate) struct Format {
+    /// Name of the format, for error messages.
+    pub(crate) name: &'static str,
+    pub(crate) parse: fn(&str) -> Result<Value, String>,
+}
+
+/// Predicate that compares a file to an expected document, ignoring formatting.
+///
+/// This is created by the `eq` function of [`toml`][super::toml] and `yaml`.
+#[derive(Debug, Clone)]
+pub struct DocumentEqPredicate {
+    format: &'static Format,
+    expected: Value,
+}
+
+impl DocumentEqPredicate {
+    #[track_caller]
+    pub(crate) fn new(format: &'static Format, expected: &str) -> Self {
+        let expected = (format.parse)(expected)
+            .unwrap_or_else(|err| panic!("expected value is not valid {}: {err}", format.name));
+        Self { format, expected }
+    }
+
+    fn read(&self, path: &path::Path) -> Result<Value, String> {
+        let content = fs::read_to_string(path).map_err(|err| err.to_string())?;
+        (self.format.parse)(&content).map_err(|err| format!("invalid {}: {err}", self.format.name))
+    }
+}
+
+impl reflection::PredicateReflection for DocumentEqPredicate {}
+
+impl predicates_core::Predicate<path::Path> for DocumentEqPredicate {
+    fn eval(&self, variable: &path::Path) -> bool {
+        self.read(variable)
+            .map(|actual| compare(&self.expected, &actual, &CompareOptions::default()).is_empty())
+            .unwrap_or(false)
+    }
+
+    fn find_case<'a>(
+        &'a self,
+        expected: bool,
+        variable: &path::Path,
+    ) -> Option<reflection::Case<'a>> {
+        find_case(
+            self,
+            expected,
+            &self.expected,
+            self.read(variable),
+            &CompareOptions::default(),
+            Mismatch::key_path,
+        )
+    }
+}
+
+impl fmt::Display for DocumentEqPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let palette = crate::Palette::color();
+        write!(
+            f,
+            "{:#} {:#} {:#}",
+            palette.key("var"),
+            palette.key("=="),
+            palette.value(self.expected.to_string())
+        )
+    }
+}
+
+/// Convert a float, keeping `nan` and `inf` apart from strings even though JSON can't represent
+/// them, by tagging them like YAML's `!!float .nan`.
+pub(crate) fn float(value: f64) -> Value {
+    if let Some(number) = serde_json::Number::from_f64(value) {
+        return Value::Number(number);
+    }
+    let value = if value.is_nan() {
+        ".nan"
+    } else if value.is_sign_positive() {
+        ".inf"
+    } else {
+        "-.inf"
+    };
+    tagged("!!float", Value::String(value.to_owned()))
+}
+
+/// Represent a value with a tag, like YAML's `!Point {x: 1}`, as an object with the tag as its
+/// only key.
+pub(crate) fn tagged(tag: impl Into<String>, value: Value) -> Value {
+    let mut object = serde_json::Map::new();
+    object.insert(tag.into(), value);
+    Value::Object(object)
+}
+
+fn compare_at(
+    expected: &Value,
+    actual: &Value,
+    options: &CompareOptions,
+    path: &mut Vec<Segment>,
+    mismatches: &mut Vec<Mismatch>,
+) {
+    if !options.ignored_pointers.is_empty() && options.ignored_pointers.contains(&pointer(path)) {
+        return;
+    }
+
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => {
+            for (key, expected) in expected {
+                if options.ignored_keys.contains(key) {
+                    continue;
+                }
+                path.push(Segment::Key(key.clone()));
+                match actual.get(key) {
+                    Some(actual) => compare_at(expected, actual, options, path, mismatches),
+                    None => push(
+                        path,
+                        MismatchKind::Missing(expected.clone()),
+                        options,
+                        mismatches,
+                    ),
+                }
+                path.pop();
+            }
+            for (key, actual) in actual {
+                if options.ignored_keys.contains(key) || expected.contains_key(key) {
+                    continue;
+                }
+                path.push(Segment::Key(key.clone()));
+                push(
+                    path,
+                    MismatchKind::Unexpected(actual.clone()),
+                    options,
+                    mismatches,
+                );
+                path.pop();
+            }
+        }
+        (Value::Array(expected), Value::Array(actual)) if options.ignore_array_order => {
+            // Each expected element takes the first unused element that matches it.  This only
+            // finds every match when matching is transitive, which ignored pointers can break.
+            let mut used = vec![false; actual.len()];
+            for (index, expected) in expected.iter().enumerate() {
+                let found = actual.iter().enumerate().position(|(i, actual)| {
+                    !used[i] && {
+                        // Pointers refer to the actual document, so ignore them at the
+                        // candidate's index.
+                        let mut scratch = Vec::new();
+                        path.push(Segment::Index(i));
+                        compare_at(expected, actual, options, path, &mut scratch);
+                        path.pop();
+                        scratch.is_empty()
+                    }
+                });
+                match found {
+                    Some(i) => used[i] = true,
+                    None => {
+                        path.push(Segment::Index(index));
+                        push(
+                            path,
+                            MismatchKind::Missing(expected.clone()),
+                            options,
+                            mismatches,
+                        );
+                        path.pop();
+                    }
+                }
+            }
+            for (index, actual) in actual.iter().enumerate() {
+                if !used[index] {
+                    path.push(Segment::Index(index));
+                    push(
+                        path,
+                        MismatchKind::Unexpected(actual.clone()),
+                        options,
+                        mismatches,
+                    );
+                    path.pop();
+                }
+            }
+        }
+        (Value::Array(expected), Value::Array(actual)) => {
+            for index in 0..expected.len().max(actual.len()) {
+                path.push(Segment::Index(index));
+                match (expected.get(index), actual.get(index)) {
+                    (Some(expected), Some(actual)) => {
+                        compare_at(expected, actual, options, path, mismatches);
+                    }
+                    (Some(expected), None) => {
+                        push(
+                            path,
+                            MismatchKind::Missing(expected.clone()),
+                            options,
+                            mismatches,
+                        );
+                    }
+                    (None, Some(actual)) => {
+                        push(
+                            path,
+                            MismatchKind::Unexpected(actual.clone()),
+                            options,
+                            mismatches,
+                        );
+                    }
+                    (None, None) => unreachable!("index is within one of the arrays"),
+                }
+                path.pop();
+            }
+        }
+        (expected, actual) => {
+            if expected != actual {
+                let kind = MismatchKind::Different {
+                    expected: expected.clone(),
+                    actual: actual.clone(),
+                };
+                push(path, kind, options, mismatches);
+            }
+        }
+    }
+}
+
+fn push(
+    path: &[Segment],
+    kind: MismatchKind,
+    options: &CompareOptions,
+    mismatches: &mut Vec<Mismatch>,
+) {
+    if options.ignored_pointers.contains(&pointer(path)) {
+        return;
+    }
+    mismatches.push(Mismatch {
+        path: path.to_vec(),
+        kind,
+    });
+}
+
+fn pointer(path: &[Segment]) -> String {
+    let mut out = String::new();
+    for segment in path {
+        out.push('/');
+        match segment {
+            Segment::Key(key) => out.push_str(&key.replace('~', "~0").replace('/', "~1")),
+            Segment::Index(index) => out.push_str(&index.to_string()),
+        }
+    }
+    out
+}
+
+fn is_bare_key(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use serde_json::json;
+
+    fn locations(expected: &Value, actual: &Value, options: &CompareOptions) -> Vec<String> {
+        compare(expected, actual, options)
+            .iter()
+            .map(|m| format!("{}: {m}", m.pointer()))
+            .collect()
+    }
+
+    #[test]
+    fn non_finite_floats() {
+        assert_eq!(float(1.5), json!(1.5));
+        assert_eq!(float(f64::NAN), json!({"!!float": ".nan"}));
+        assert_eq!(float(f64::INFINITY), json!({"!!float": ".inf"}));
+        assert_eq!(float(f64::NEG_INFINITY), json!({"!!float": "-.inf"}));
+    }
+
+    #[test]
+    fn reports_every_mismatch() {
+        let expected = json!({"a": 1, "b": [1, 2], "c": {"d": "x"}, "e/f": true});
+        let actual = json!({"a": 2, "b": [1], "c": {"d": "x", "g": null}, "e/f": true});
+        assert_eq!(
+            locations(&expected, &actual, &CompareOptions::default()),
+            [
+                "/a: expected `1`, found `2`",
+                "/b/1: missing, expected `2`",
+                "/c/g: unexpected `null`",
+            ]
+        );
+    }
+
+    #[test]
+    fn ignore_array_order() {
+        let expected = json!([{"id": 1}, {"id": 2}, 3]);
+        let actual = json!([3, {"id": 2}, {"id": 4}]);
+        let options = CompareOptions {
+            ignore_array_order: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            locations(&expected, &actual, &options),
+            [
+                "/0: missing, expected `{\"id\":1}`",
+                "/2: unexpected `{\"id\":4}`"
+            ]
+        );
+    }
+
+    #[test]
+    fn ignore_pointer_in_unordered_array() {
+        let expected = json!({"items": [{"id": 2, "t": 0}, {"id": 1, "t": 0}]});
+        let actual = json!({"items": [{"id": 1, "t": 5}, {"id": 2, "t": 6}]});
+        let options = CompareOptions {
+            ignore_array_order: true,
+            ignored_pointers: vec!["/items/0/t".to_owned(), "/items/1/t".to_owned()],
+            ..Default::default()
+        };
+        assert!(locations(&expected, &actual, &options).is_empty());
+    }
+
+    #[test]
+    fn ignored() {
+        let expected = json!({"time": 1, "a": {"time": 2, "b": 3}, "c": 4});
+        let actual = json!({"time": 5, "a": {"b": 3}, "c": 6});
+        let options = CompareOptions {
+            ignored_keys: vec!["time".to_owned()],
+            ignored_pointers: vec!["/c".to_owned()],
+            ..Default::default()
+        };
+        assert!(locations(&expected, &actual, &options).is_empty());
+    }
+
+    #[test]
+    fn key_path() {
+        let expected = json!({"package": {"authors": ["a"], "a.b": 1}});
+        let actual = json!({"package": {"authors": ["b"], "a.b": 2}});
+        let paths: Vec<_> = compare(&expected, &actual, &CompareOptions::default())
+            .iter()
+            .map(Mismatch::key_path)
+            .collect();
+        assert_eq!(paths, ["package.\"a.b\"", "package.authors[0]"]);
+    }
+}
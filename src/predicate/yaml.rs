@@ -0,0 +1,154 @@
+//! YAML content predicates.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use assert_fs::prelude::*;
+//! use assert_fs::predicate::yaml;
+//!
+//! let temp = assert_fs::TempDir::new().unwrap();
+//! let workflow = temp.child("ci.yml");
+//! workflow
+//!     .write_str("on: [push]\njobs:\n  test:\n    runs-on: ubuntu-latest # default\n")
+//!     .unwrap();
+//!
+//! workflow.assert(yaml::eq("jobs: {test: {runs-on: ubuntu-latest}}\non:\n  - push\n"));
+//!
+//! temp.close().unwrap();
+//! ```
+
+use serde_json::Value;
+
+use super::value;
+use super::value::DocumentEqPredicate;
+use super::value::Format;
+
+static FORMAT: Format = Format {
+    name: "YAML",
+    parse: |content| parse(content).map_err(|err| err.to_string()),
+};
+
+/// Creates a new predicate that parses a file as YAML and compares it to `expected`.
+///
+/// Formatting, comments, key order, and anchors are ignored, with merge keys (`<<`) applied.
+/// Tags are kept, comparing `!Point {x: 1}` like `{"!Point": {x: 1}}`.  Mismatches are reported
+/// by their key path, like `jobs.test.steps[0]`.
+///
+/// # Panics
+///
+/// If `expected` is not valid YAML.
+///
+/// # Examples
+///
+/// ```rust
+/// use assert_fs::prelude::*;
+/// use assert_fs::predicate::yaml;
+/// use predicates::prelude::*;
+///
+/// let temp = assert_fs::TempDir::new().unwrap();
+/// let config = temp.child("config.yml");
+/// config.write_str("name: foo\nretries: 3\n").unwrap();
+///
+/// config.assert(yaml::eq("{retries: 3, name: foo}"));
+/// config.assert(yaml::eq("{retries: 1, name: foo}").not());
+///
+/// temp.close().unwrap();
+/// ```
+#[track_caller]
+pub fn eq(expected: &str) -> YamlEqPredicate {
+    DocumentEqPredicate::new(&FORMAT, expected)
+}
+
+/// Predicate that compares a YAML file to an expected document, ignoring formatting.
+///
+/// This is created by [`eq`].
+pub type YamlEqPredicate = DocumentEqPredicate;
+
+fn parse(content: &str) -> Result<Value, serde_yaml_ng::Error> {
+    let mut value = serde_yaml_ng::from_str::<serde_yaml_ng::Value>(content)?;
+    value.apply_merge()?;
+    Ok(convert(value))
+}
+
+fn convert(value: serde_yaml_ng::Value) -> Value {
+    match value {
+        serde_yaml_ng::Value::Null => Value::Null,
+        serde_yaml_ng::Value::Bool(value) => Value::Bool(value),
+        serde_yaml_ng::Value::Number(value) => {
+            if let Some(value) = value.as_i64() {
+                Value::from(value)
+            } else if let Some(value) = value.as_u64() {
+                Value::from(value)
+            } else {
+                value::float(value.as_f64().unwrap_or(f64::NAN))
+            }
+        }
+        serde_yaml_ng::Value::String(value) => Value::String(value),
+        serde_yaml_ng::Value::Sequence(values) => {
+            Value::Array(values.into_iter().map(convert).collect())
+        }
+        serde_yaml_ng::Value::Mapping(mapping) => Value::Object(
+            mapping
+                .into_iter()
+                .map(|(key, value)| (key_to_string(key), convert(value)))
+                .collect(),
+        ),
+        serde_yaml_ng::Value::Tagged(tagged) => {
+            value::tagged(tagged.tag.to_string(), convert(tagged.value))
+        }
+    }
+}
+
+fn key_to_string(key: serde_yaml_ng::Value) -> String {
+    match key {
+        serde_yaml_ng::Value::String(key) => key,
+        key => serde_yaml_ng::to_string(&key)
+            .map(|key| key.trim_end().to_owned())
+            .unwrap_or_default(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::fs;
+
+    use predicates_core::Predicate;
+    use predicates_tree::CaseTreeExt;
+
+    #[test]
+    fn reports_key_paths() {
+        let temp = crate::TempDir::new().unwrap();
+        let path = temp.path().join("ci.yml");
+        fs::write(
+            &path,
+            concat!(
+                "defaults: &defaults\n  os: linux\n",
+                "jobs:\n  test:\n    <<: *defaults\n    steps: [build, test]\n",
+            ),
+        )
+        .unwrap();
+
+        let pred = eq("defaults: {os: linux}\njobs: {test: {os: windows, steps: [build, test]}}");
+        let output = pred.find_case(false, &path).unwrap().tree().to_string();
+        assert!(
+            output.contains("jobs.test.os: expected `\"windows\"`, found `\"linux\"`"),
+            "{output}"
+        );
+        assert_eq!(output.matches("expected").count(), 1, "{output}");
+    }
+
+    #[test]
+    fn tags() {
+        let temp = crate::TempDir::new().unwrap();
+        let path = temp.path().join("config.yml");
+        fs::write(&path, "a: !Foo 1\nb: .nan\n").unwrap();
+
+        assert!(eq("{a: !Foo 1, b: .NaN}").eval(&path));
+        assert!(!eq("{a: 1, b: .nan}").eval(&path));
+        assert!(!eq("{a: !Bar 1, b: .nan}").eval(&path));
+        assert!(!eq("{a: !Foo 1, b: .nan string}").eval(&path));
+        assert!(!eq("{a: !Foo 1, b: '.nan'}").eval(&path));
+    }
+}